@@ -1,7 +1,8 @@
 use crate::types::*;
-use crate::Transposition;
+use crate::{is_bell_name, name_to_number, CoursingOrder, NamedRow, PlaceNotation, Transposition};
 use core::ops::{Mul, Not};
 use std::convert::From;
+use std::error;
 use std::fmt;
 
 // Imports used purely to prevent lots of boiler plate code in the documentation.  These won't be
@@ -38,6 +39,12 @@ use crate::{Row, Touch};
 ///
 /// assert_eq!(cyclic_part_head * some_change, Change::from("28713456"));
 /// ```
+///
+/// `Change`s are ordered lexicographically by their [Bell]s, so sorting a list of `Change`s of the
+/// same [Stage] will put them in the order that they'd appear in a dictionary (with rounds first).
+/// This order is also used when `Change`s are keys of ordered collections such as
+/// [BTreeMap](std::collections::BTreeMap).  A `Change` that is the start of a longer `Change` is
+/// ordered before it, so rounds on a lower [Stage] comes before rounds on a higher one.
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Change {
     seq: Vec<Bell>,
@@ -77,7 +84,162 @@ impl Change {
     /// assert_eq!(Change::rounds(Stage::CATERS), Change::from("123456789"));
     /// ```
     pub fn rounds(stage: Stage) -> Change {
-        Change::from_iterator((0..stage.as_usize()).map(Bell::from))
+        Change {
+            seq: Bell::rounds_vec(stage),
+        }
+    }
+
+    /// Returns the cyclic part end on a given [Stage], where every [Bell] in `fixed` stays in its
+    /// home position and the remaining [Bell]s each move round by one position (so that the order
+    /// of the `Change` is the number of moving [Bell]s).
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Bell, Change, Stage};
+    ///
+    /// assert_eq!(Change::cyclic(Stage::TRIPLES, &[Bell::from('1')]), Change::from("1345672"));
+    /// assert_eq!(
+    ///     Change::cyclic(Stage::MAJOR, &[Bell::from('1'), Bell::from('2')]),
+    ///     Change::from("12456783")
+    /// );
+    /// assert_eq!(Change::cyclic(Stage::MINOR, &[]), Change::from("234561"));
+    /// ```
+    pub fn cyclic(stage: Stage, fixed: &[Bell]) -> Change {
+        let moving_bells: Vec<Bell> = (0..stage.as_usize())
+            .map(Bell::from)
+            .filter(|b| !fixed.contains(b))
+            .collect();
+
+        let mut change = Change::rounds(stage);
+
+        for (i, b) in moving_bells.iter().enumerate() {
+            change.seq[b.as_usize()] = moving_bells[(i + 1) % moving_bells.len()];
+        }
+
+        change
+    }
+
+    /// Builds a `Change` from disjoint cycles of [Bell]s, where each [Bell] in a cycle is replaced
+    /// by the next [Bell] in that cycle (so the [Place] of the first [Bell] contains the second
+    /// [Bell], and so on).  This is the inverse of [cycles](Change::cycles).
+    ///
+    /// # Panics
+    /// Panics if the cycles don't contain every [Bell] of `stage` exactly once.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Bell, Change, Stage};
+    ///
+    /// let cycles: Vec<Vec<Bell>> = vec!["1", "234", "5", "6"]
+    ///     .iter()
+    ///     .map(|c| c.chars().map(Bell::from).collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(Change::from_cycles(Stage::MINOR, &cycles), Change::from("134256"));
+    /// ```
+    pub fn from_cycles(stage: Stage, cycles: &[Vec<Bell>]) -> Change {
+        let n = stage.as_usize();
+        let mut change = Change::rounds(stage);
+        let mut bells_seen = Mask::empty();
+
+        for cycle in cycles {
+            for (i, b) in cycle.iter().enumerate() {
+                if b.as_usize() >= n {
+                    panic!(
+                        "Bell '{}' is too large for a cycle on this stage",
+                        b.as_char()
+                    );
+                }
+
+                if bells_seen.get(b.as_number()) {
+                    panic!("Bell '{}' appears in more than one cycle", b.as_char());
+                }
+
+                bells_seen.add(b.as_number());
+                change.seq[b.as_usize()] = cycle[(i + 1) % cycle.len()];
+            }
+        }
+
+        if (0..n).any(|b| !bells_seen.get(b as Number)) {
+            panic!("The cycles don't contain every bell of the stage");
+        }
+
+        change
+    }
+
+    /// Splits this `Change` into its disjoint cycles, in the form used by
+    /// [from_cycles](Change::from_cycles).  Every [Bell] appears in exactly one cycle (so [Bell]s
+    /// that stay in their home positions are in cycles of their own).  Each cycle starts with its
+    /// smallest [Bell], and the cycles are sorted by their first [Bell]s.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Bell, Change};
+    ///
+    /// assert_eq!(
+    ///     Change::from("134256").cycles(),
+    ///     vec![
+    ///         vec![Bell::from('1')],
+    ///         vec![Bell::from('2'), Bell::from('3'), Bell::from('4')],
+    ///         vec![Bell::from('5')],
+    ///         vec![Bell::from('6')],
+    ///     ]
+    /// );
+    /// ```
+    pub fn cycles(&self) -> Vec<Vec<Bell>> {
+        let mut cycles = Vec::new();
+        let mut bells_seen = Mask::empty();
+
+        for start in 0..self.seq.len() {
+            if bells_seen.get(start as Number) {
+                continue;
+            }
+
+            let mut cycle = Vec::new();
+            let mut b = Bell::from(start);
+
+            while !bells_seen.get(b.as_number()) {
+                bells_seen.add(b.as_number());
+                cycle.push(b);
+
+                b = self.seq[b.as_usize()];
+            }
+
+            cycles.push(cycle);
+        }
+
+        cycles
+    }
+
+    /// Returns the row reached after ringing `n` changes of plain hunt on a given [Stage],
+    /// starting from rounds.  The first change swaps the [Bell]s in the first two places (i.e.
+    /// `x` on even stages or `n` on odd stages), and then the changes alternate with making
+    /// lead.  The rows repeat every `2 * stage` changes.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, Stage};
+    ///
+    /// assert_eq!(Change::hunt_steps(Stage::MINOR, 0), Change::from("123456"));
+    /// assert_eq!(Change::hunt_steps(Stage::MINOR, 1), Change::from("214365"));
+    /// assert_eq!(Change::hunt_steps(Stage::MINOR, 2), Change::from("241635"));
+    /// assert_eq!(Change::hunt_steps(Stage::DOUBLES, 3), Change::from("42513"));
+    /// ```
+    pub fn hunt_steps(stage: Stage, n: usize) -> Change {
+        let mut change = Change::rounds(stage);
+        let stage = stage.as_usize();
+
+        for i in 0..n % (2 * stage).max(1) {
+            let mut place = i & 1;
+
+            while place + 1 < stage {
+                change.seq.swap(place, place + 1);
+
+                place += 2;
+            }
+        }
+
+        change
     }
 
     /// Creates a new `Change`, given a vector of [Bell]s that it should contain.
@@ -125,6 +287,34 @@ impl Change {
         Stage::from(self.seq.len())
     }
 
+    /// Returns an iterator over the [Place]s of this `Change` along with the [Bell] in each one,
+    /// from lead to back.  This is double-ended, so `.rev()` iterates efficiently from the back
+    /// instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use bellmetal::{Bell, Change, Place};
+    ///
+    /// let c = Change::from("132");
+    ///
+    /// assert_eq!(
+    ///     c.enumerate().rev().collect::<Vec<_>>(),
+    ///     vec![
+    ///         (Place::from(2), Bell::from('2')),
+    ///         (Place::from(1), Bell::from('3')),
+    ///         (Place::from(0), Bell::from('1')),
+    ///     ]
+    /// );
+    /// ```
+    pub fn enumerate(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Place, Bell)> + ExactSizeIterator + '_ {
+        self.seq
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (Place::from(i), *b))
+    }
+
     /// Returns a mutable slice from the underlying representation of a given `Change`.  This is
     /// designed mainly for internal use - if you really want to modify `Change`s then
     /// [set_bell](Change::set_bell) is a safer (but marginally slower) way to do so.  However,
@@ -173,6 +363,104 @@ impl Change {
         c
     }
 
+    /// Multiplies this `Change` on the right by another [Transposition], returning `self * rhs`.
+    /// This is exactly the same as [multiply](Change::multiply) (and therefore the `*` operator),
+    /// but makes it explicit which side `rhs` goes on.  In terms of touches, this is the effect of
+    /// ringing `self` followed by `rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// let part_head = Change::from("17823456");
+    /// let some_change = Change::from("43215678");
+    ///
+    /// assert_eq!(part_head.post_multiply(&some_change), Change::from("28713456"));
+    /// assert_eq!(
+    ///     part_head.post_multiply(&some_change),
+    ///     part_head.clone() * some_change.clone()
+    /// );
+    /// ```
+    pub fn post_multiply(&self, rhs: &impl Transposition) -> Change {
+        self.multiply(rhs)
+    }
+
+    /// Multiplies this `Change` on the left by another [Transposition], returning `lhs * self`.
+    /// In terms of touches, this is the effect of ringing `lhs` followed by `self` - for example,
+    /// pre-multiplying a row of the plain course by a course head gives the same row in that
+    /// course.
+    ///
+    /// # Panics
+    /// Panics if `lhs` doesn't have the same [Stage] as this `Change`.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// let part_head = Change::from("17823456");
+    /// let some_change = Change::from("43215678");
+    ///
+    /// assert_eq!(some_change.pre_multiply(&part_head), Change::from("28713456"));
+    /// assert_eq!(
+    ///     some_change.pre_multiply(&part_head),
+    ///     part_head.clone() * some_change.clone()
+    /// );
+    /// ```
+    pub fn pre_multiply(&self, lhs: &impl Transposition) -> Change {
+        let mut c = Change::empty();
+
+        self.pre_multiply_into(lhs, &mut c);
+
+        c
+    }
+
+    /// Returns the conjugate of this `Change` by another `Change`, i.e. `by.inverse() * self * by`.
+    /// The conjugate has the same cycle structure as this `Change`, but with the [Bell]s relabelled
+    /// by `by` - for example, conjugating a part end by a course head gives the part end that
+    /// produces the same composition rung from that course head.
+    ///
+    /// # Panics
+    /// Panics if `by` doesn't have the same [Stage] as this `Change`.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, Stage};
+    ///
+    /// let part_end = Change::from("13425678");
+    ///
+    /// assert_eq!(part_end.conjugate(&Change::from("21345678")), Change::from("32415678"));
+    /// assert_eq!(part_end.conjugate(&Change::rounds(Stage::MAJOR)), part_end);
+    /// ```
+    pub fn conjugate(&self, by: &Change) -> Change {
+        if by.stage() != self.stage() {
+            panic!(
+                "Can't conjugate a change of stage {} by one of stage {}",
+                self.stage().as_usize(),
+                by.stage().as_usize()
+            );
+        }
+
+        by.inverse().multiply(self).multiply(by)
+    }
+
+    /// Returns the [Bell] in a given [Place] of this `Change`, or `None` if the [Place] is beyond
+    /// the end of the `Change`.  This is the non-panicking version of
+    /// [bell_at](Transposition::bell_at).
+    ///
+    /// # Examples
+    /// ```
+    /// use bellmetal::{Bell, Change, Place};
+    ///
+    /// let c = Change::from("2143");
+    ///
+    /// assert_eq!(c.get(Place::from(0)), Some(Bell::from('2')));
+    /// assert_eq!(c.get(Place::from(3)), Some(Bell::from('3')));
+    /// assert_eq!(c.get(Place::from(4)), None);
+    /// ```
+    pub fn get(&self, place: Place) -> Option<Bell> {
+        self.seq.get(place.as_usize()).copied()
+    }
+
     /// Overwrites a [Bell] in a given [Place] in a given `Change`.
     ///
     /// # Examples
@@ -280,6 +568,78 @@ impl Change {
         }
     }
 
+    /// Applies a [PlaceNotation] to this `Change` in place, swapping every pair of [Bell]s that
+    /// aren't making places.  This gives the next row without allocating a new `Change`, so can be
+    /// used to step through a [Touch] one row at a time.
+    ///
+    /// # Panics
+    /// Panics if `pn` doesn't have the same [Stage] as this `Change`.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, PlaceNotation, Stage};
+    ///
+    /// let mut row = Change::rounds(Stage::MINOR);
+    ///
+    /// row.step(&PlaceNotation::from_str("x", Stage::MINOR));
+    /// assert_eq!(row, Change::from("214365"));
+    ///
+    /// row.step(&PlaceNotation::from_str("16", Stage::MINOR));
+    /// assert_eq!(row, Change::from("241635"));
+    /// ```
+    pub fn step(&mut self, pn: &PlaceNotation) {
+        let stage = self.seq.len();
+
+        if pn.stage.as_usize() != stage {
+            panic!(
+                "Can't apply place notation of stage {} to a change of stage {}",
+                pn.stage.as_usize(),
+                stage
+            );
+        }
+
+        let mut place = 0;
+
+        while place < stage {
+            if pn.places.get(place as Number) || place + 1 == stage {
+                place += 1;
+            } else {
+                self.seq.swap(place, place + 1);
+
+                place += 2;
+            }
+        }
+    }
+
+    /// Returns the row reached by applying a cross change to this `Change`, i.e. swapping the
+    /// [Bell]s in every pair of places `(1, 2), (3, 4), ...`.  This gives the same result as
+    /// multiplying by the transposition of [PlaceNotation::cross], but without building a
+    /// [PlaceNotation].
+    ///
+    /// # Panics
+    /// Panics if this `Change` has an odd [Stage], since cross changes only exist on even stages.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, Stage};
+    ///
+    /// assert_eq!(Change::rounds(Stage::MINOR).cross_applied(), Change::from("214365"));
+    /// assert_eq!(Change::from("241635").cross_applied(), Change::from("426153"));
+    /// ```
+    pub fn cross_applied(&self) -> Change {
+        if self.seq.len() & 1 != 0 {
+            panic!("Can't apply a cross change to a change of odd stage");
+        }
+
+        let mut change = self.clone();
+
+        for pair in change.seq.chunks_exact_mut(2) {
+            pair.swap(0, 1);
+        }
+
+        change
+    }
+
     /// Multiplies this `Change` by an iterator, and copies the result into another `Change` to
     /// avoid heap allocations.  Same as [multiply_iterator](Change::multiply_iterator), except
     /// that it will avoid allocating a new `Change` on the heap if an old one can be reused.
@@ -429,6 +789,68 @@ impl Change {
         }
     }
 
+    /// Creates a `Change` from a string of [Bell] names, checking that it is a valid row (i.e. it
+    /// contains every [Bell] up to its length exactly once).  Unlike `Change::from`, which will
+    /// happily store any sequence of [Bell]s (such as coursing orders, or the result of
+    /// [multiply_iterator](Change::multiply_iterator)), this returns an [InvalidRowError] if the
+    /// string isn't a row.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Bell, Change, InvalidRowError};
+    ///
+    /// assert_eq!(Change::try_from_str("135246"), Ok(Change::from("135246")));
+    /// assert_eq!(
+    ///     Change::try_from_str("136"),
+    ///     Err(InvalidRowError::BellOutOfRange(Bell::from('6')))
+    /// );
+    /// assert_eq!(
+    ///     Change::try_from_str("1223"),
+    ///     Err(InvalidRowError::RepeatedBell(Bell::from('2')))
+    /// );
+    /// assert_eq!(
+    ///     Change::try_from_str("12?"),
+    ///     Err(InvalidRowError::IllegalBell('?'))
+    /// );
+    /// ```
+    pub fn try_from_str(string: &str) -> Result<Change, InvalidRowError> {
+        let mut seq: Vec<Bell> = Vec::with_capacity(string.len());
+
+        for c in string.chars() {
+            if !is_bell_name(c) {
+                return Err(InvalidRowError::IllegalBell(c));
+            }
+
+            seq.push(Bell::from(name_to_number(c)));
+        }
+
+        let change = Change { seq };
+
+        change.check_is_row()?;
+
+        Ok(change)
+    }
+
+    // Checks that every bell in this `Change` is within its stage, and that none are repeated
+    fn check_is_row(&self) -> Result<(), InvalidRowError> {
+        let stage = self.seq.len();
+        let mut bells_seen = Mask::empty();
+
+        for b in &self.seq {
+            if b.as_usize() >= stage {
+                return Err(InvalidRowError::BellOutOfRange(*b));
+            }
+
+            if bells_seen.get(b.as_number()) {
+                return Err(InvalidRowError::RepeatedBell(*b));
+            }
+
+            bells_seen.add(b.as_number());
+        }
+
+        Ok(())
+    }
+
     /// Replaces the contents of this `Change` with the contents of `iter`.  As with
     /// [overwrite_from_string](Change::overwrite_from_string), it will change the [Stage] of this
     /// `Change` to be the same as the length of the iterator.  This will hang forever if the
@@ -668,65 +1090,340 @@ impl Change {
         }
     }
 
-    /// Hashes the change into the range `0..(stage)!`, but in the process it will destroy the
-    /// contents of `Change`.  In fact, it cannot be guarunteed that this function leaves this
-    /// `Change` representing a valid permutation.
-    ///
-    /// Useful when proof checking on lower numbers, by creating a blank
-    /// table with a space for every possible change and then iterating over every row, filling in
-    /// the corresponding slot on the table.
+    /// Returns `true` if this `Change` matches `pattern`, which gives the name of the [Bell] that
+    /// must be in each place, or `x` for places that can contain any [Bell].  Patterns of the
+    /// wrong length never match.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// assert!(Change::from("14325678").matches_pattern("1x3xxxxx"));
+    /// assert!(Change::from("12345678").matches_pattern("xxxxx678"));
+    /// assert!(!Change::from("31245678").matches_pattern("1x3xxxxx"));
+    /// assert!(!Change::from("123456").matches_pattern("1x3xxxxx"));
+    /// ```
+    pub fn matches_pattern(&self, pattern: &str) -> bool {
+        pattern.chars().count() == self.seq.len()
+            && pattern
+                .chars()
+                .zip(self.seq.iter())
+                .all(|(c, b)| c == 'x' || (is_bell_name(c) && name_to_number(c) == b.as_number()))
+    }
+
+    /// Returns the names of the [Bell]s in this `Change`, separated by `separator` (unlike
+    /// [Display](fmt::Display), which gives them with no separators between them).  This is
+    /// useful for exporting rows to formats such as CSV.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// assert_eq!(Change::from("13524").to_delimited(','), "1,3,5,2,4");
+    /// assert_eq!(Change::from("1").to_delimited(','), "1");
+    /// assert_eq!(Change::empty().to_delimited(','), "");
+    /// ```
+    pub fn to_delimited(&self, separator: char) -> String {
+        let mut string = String::with_capacity(self.seq.len() * 2);
+
+        for (i, b) in self.seq.iter().enumerate() {
+            if i > 0 {
+                string.push(separator);
+            }
+
+            string.push(b.as_char());
+        }
+
+        string
+    }
+
+    /// Returns the [Bell]s of this `Change` as the numbers that ringers would give them, so the
+    /// treble is `1` (unlike [Bell::as_number], which counts from `0`).  This is useful for
+    /// exporting rows to tools which expect numeric rows.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// assert_eq!(Change::from("132").to_numbers(), vec![1, 3, 2]);
+    /// assert_eq!(Change::from("1234567890ET").to_numbers()[11], 12);
+    /// ```
+    pub fn to_numbers(&self) -> Vec<u8> {
+        self.seq.iter().map(|b| b.as_usize() as u8 + 1).collect()
+    }
+
+    /// Returns the coursing order of the course with this `Change` as its course head, written
+    /// the way conductors write it: starting with the [Bell] that courses the heaviest [Bell]
+    /// and finishing with the heaviest [Bell] itself.  Like
+    /// [CoursingOrder::from_leadhead], this assumes Plain Bob lead heads.  The `Change` is
+    /// extended to `stage` with [Bell]s in their home positions, so that course heads can be
+    /// written without their covers.
+    ///
+    /// # Panics
+    /// Panics if `stage` is smaller than the [Stage] of this `Change`.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, Stage};
+    ///
+    /// assert_eq!(Change::rounds(Stage::MINOR).coursing_order_string(Stage::MINOR), "53246");
+    /// assert_eq!(Change::from("1342").coursing_order_string(Stage::MAJOR), "7543268");
+    /// ```
+    pub fn coursing_order_string(&self, stage: Stage) -> String {
+        if stage < self.stage() {
+            panic!(
+                "Can't write a coursing order of {} for a change of {}",
+                stage,
+                self.stage()
+            );
+        }
+
+        let course_head = Change::from_iterator(
+            self.seq
+                .iter()
+                .cloned()
+                .chain((self.seq.len()..stage.as_usize()).map(Bell::from)),
+        );
+        let coursing_order = CoursingOrder::from_leadhead(&course_head);
+        let mut string = String::new();
+
+        coursing_order.write_to_string(&mut string);
+
+        // The coursing order starts with its heaviest bell, which conductors write last
+        if !string.is_empty() {
+            let heaviest_bell = string.remove(0);
+
+            string.push(heaviest_bell);
+        }
+
+        string
+    }
+
+    /// Returns `true` if the treble (the [Bell] `1`) is leading in this `Change`.  This is a cheap
+    /// check for filtering lead heads of treble-dominated methods.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, Stage};
+    ///
+    /// assert!(Change::rounds(Stage::MAJOR).treble_in_lead());
+    /// assert!(Change::from("15738264").treble_in_lead());
+    /// assert!(!Change::from("21345678").treble_in_lead());
+    /// ```
+    pub fn treble_in_lead(&self) -> bool {
+        self.seq.first() == Some(&Bell::from(0))
+    }
+
+    /// Returns `true` if reversing the order of this `Change`'s places gives back the same
+    /// `Change`.  Since no [Bell] can appear twice in a `Change`, this can only happen on stages
+    /// with at most one bell, so rounds is not self-reverse on any larger stage.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// assert!(Change::from("1").is_self_reverse());
+    /// assert!(!Change::from("12345678").is_self_reverse());
+    /// assert!(!Change::from("14325").is_self_reverse());
+    /// ```
+    pub fn is_self_reverse(&self) -> bool {
+        self.seq.iter().eq(self.seq.iter().rev())
+    }
+
+    /// Hashes the change into the range `0..(stage)!`, but in the process it will destroy the
+    /// contents of `Change`.  In fact, it cannot be guarunteed that this function leaves this
+    /// `Change` representing a valid permutation.
+    ///
+    /// Useful when proof checking on lower numbers, by creating a blank
+    /// table with a space for every possible change and then iterating over every row, filling in
+    /// the corresponding slot on the table.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// let mut c = Change::from("14723865");
+    ///
+    /// assert_eq!(c.destructive_hash(), 26939);
+    /// assert_ne!(c, Change::from("14723865"));
+    /// ```
+    pub fn destructive_hash(&mut self) -> usize {
+        let stage = self.seq.len();
+
+        // Initialise multiplier to (stage - 1)!
+        let mut multiplier = 1;
+        for i in 1..stage {
+            multiplier *= i;
+        }
+
+        let mut i = stage - 1;
+        let mut hash = 0;
+
+        // Invariant:
+        //   1) self.seq[..i] is a valid permutation
+        //   2) multiplier = i!
+        //   3) destructive_hash(self.seq[..i]) + hash = x
+        //           where x is the output of the function
+        while i > 0 {
+            // Iterate to find the index of the bell represented by `i`
+            for j in 0..stage {
+                // Check if we've found the right bell
+                if self.seq[j] == Bell::from(i) {
+                    // Add the bell's index into our hash
+                    hash += j * multiplier;
+
+                    // Here, we could (and should for clarity) swap `self.seq[j]` and
+                    // `self.seq[i]`, but since `seq[i]` will never be read, it is a waste of time
+                    // setting it to any particular value.
+                    self.seq[j] = self.seq[i];
+
+                    // Break the loop, since the inner loop has done its work already
+                    break;
+                }
+            }
+
+            // Update both `i` and `multiplier` to preserve Invariant #2
+            multiplier /= i;
+            i -= 1;
+        }
+
+        // Return the accumulated hash value
+        hash
+    }
+
+    /// Hashes this `Change` using 64-bit FNV-1a over the 0-indexed numbers of its [Bell]s (one
+    /// byte per [Bell]).  Unlike the [Hash](std::hash::Hash) implementation (whose output depends
+    /// on the hasher, which is usually randomly seeded), this hash is guaranteed to be the same on
+    /// every run of every program and on every platform, so it is safe to persist - for example,
+    /// when saving truth tables to disk.  This algorithm will not change without a major version
+    /// bump.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, Stage};
+    ///
+    /// let rounds = Change::rounds(Stage::MAJOR);
+    ///
+    /// assert_eq!(rounds.stable_hash(), rounds.stable_hash());
+    /// assert_eq!(Change::from("12345678").stable_hash(), rounds.stable_hash());
+    /// assert_ne!(Change::from("21345678").stable_hash(), rounds.stable_hash());
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = FNV_OFFSET_BASIS;
+
+        for b in &self.seq {
+            hash ^= u64::from(b.as_number() as u8);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+
+        hash
+    }
+
+    /// Returns the number of places in which this `Change` and another [Transposition] contain
+    /// different [Bell]s.
+    ///
+    /// # Panics
+    /// Panics if `other` doesn't have the same [Stage] as this `Change`.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// assert_eq!(Change::from("12345678").distance(&Change::from("12345678")), 0);
+    /// assert_eq!(Change::from("12345678").distance(&Change::from("13245678")), 2);
+    /// assert_eq!(Change::from("12345678").distance(&Change::from("87654321")), 8);
+    /// ```
+    pub fn distance(&self, other: &impl Transposition) -> usize {
+        assert_eq!(self.stage(), other.stage());
+
+        self.seq
+            .iter()
+            .zip(other.slice().iter())
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    /// Returns every [NamedRow] that is defined on a given [Stage], along with the `Change` that
+    /// it refers to on that [Stage].  These are in the same order as [NamedRow::ALL], without the
+    /// rows that don't exist on `stage` (e.g. Whittingtons on odd stages).
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, NamedRow, Stage};
+    ///
+    /// let rows = Change::standard_rows(Stage::MAJOR);
+    ///
+    /// assert_eq!(rows.len(), 6);
+    /// assert!(rows.contains(&(NamedRow::Queens, Change::from("13572468"))));
+    /// assert_eq!(Change::standard_rows(Stage::TRIPLES).len(), 5);
+    /// ```
+    pub fn standard_rows(stage: Stage) -> Vec<(NamedRow, Change)> {
+        NamedRow::ALL
+            .iter()
+            .filter_map(|r| r.change(stage).map(|c| (*r, c)))
+            .collect()
+    }
+
+    /// Returns the [NamedRow] which is closest to this `Change` (as measured by
+    /// [distance](Change::distance)), along with that distance.  If several named rows are
+    /// equally close, the one that comes first in [NamedRow::ALL] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::{Change, NamedRow};
+    ///
+    /// assert_eq!(Change::from("13572468").nearest_named_row(), (NamedRow::Queens, 0));
+    /// assert_eq!(Change::from("13574268").nearest_named_row(), (NamedRow::Queens, 2));
+    /// assert_eq!(Change::from("12345687").nearest_named_row(), (NamedRow::Rounds, 2));
+    /// ```
+    pub fn nearest_named_row(&self) -> (NamedRow, usize) {
+        Change::standard_rows(self.stage())
+            .into_iter()
+            .map(|(r, c)| (r, self.distance(&c)))
+            .min_by_key(|(_, distance)| *distance)
+            .unwrap()
+    }
+
+    /// Returns the length of the longest run (ascending or descending) starting at the front of
+    /// this `Change`.  Unlike [run_length_off_front](Transposition::run_length_off_front), a lone
+    /// [Bell] doesn't count as a run, so this returns `0` if the first two [Bell]s aren't
+    /// adjacent.
+    ///
+    /// # Example
+    /// ```
+    /// use bellmetal::Change;
+    ///
+    /// assert_eq!(Change::from("12346578").front_run_length(), 4);
+    /// assert_eq!(Change::from("65432178").front_run_length(), 6);
+    /// assert_eq!(Change::from("13246578").front_run_length(), 0);
+    /// ```
+    pub fn front_run_length(&self) -> usize {
+        match self.run_length_off_front() {
+            1 => 0,
+            x => x,
+        }
+    }
+
+    /// Returns the length of the longest run (ascending or descending) finishing at the back of
+    /// this `Change`.  Like [front_run_length](Change::front_run_length), this returns `0` if the
+    /// last two [Bell]s aren't adjacent.
     ///
     /// # Example
     /// ```
     /// use bellmetal::Change;
     ///
-    /// let mut c = Change::from("14723865");
-    ///
-    /// assert_eq!(c.destructive_hash(), 26939);
-    /// assert_ne!(c, Change::from("14723865"));
+    /// assert_eq!(Change::from("12346578").back_run_length(), 2);
+    /// assert_eq!(Change::from("34125678").back_run_length(), 4);
+    /// assert_eq!(Change::from("12345768").back_run_length(), 0);
     /// ```
-    pub fn destructive_hash(&mut self) -> usize {
-        let stage = self.seq.len();
-
-        // Initialise multiplier to (stage - 1)!
-        let mut multiplier = 1;
-        for i in 1..stage {
-            multiplier *= i;
-        }
-
-        let mut i = stage - 1;
-        let mut hash = 0;
-
-        // Invariant:
-        //   1) self.seq[..i] is a valid permutation
-        //   2) multiplier = i!
-        //   3) destructive_hash(self.seq[..i]) + hash = x
-        //           where x is the output of the function
-        while i > 0 {
-            // Iterate to find the index of the bell represented by `i`
-            for j in 0..stage {
-                // Check if we've found the right bell
-                if self.seq[j] == Bell::from(i) {
-                    // Add the bell's index into our hash
-                    hash += j * multiplier;
-
-                    // Here, we could (and should for clarity) swap `self.seq[j]` and
-                    // `self.seq[i]`, but since `seq[i]` will never be read, it is a waste of time
-                    // setting it to any particular value.
-                    self.seq[j] = self.seq[i];
-
-                    // Break the loop, since the inner loop has done its work already
-                    break;
-                }
-            }
-
-            // Update both `i` and `multiplier` to preserve Invariant #2
-            multiplier /= i;
-            i -= 1;
+    pub fn back_run_length(&self) -> usize {
+        match self.run_length_off_back() {
+            1 => 0,
+            x => x,
         }
-
-        // Return the accumulated hash value
-        hash
     }
 }
 
@@ -759,7 +1456,8 @@ impl Not for Change {
 }
 
 impl From<&str> for Change {
-    /// Converts a string of valid `Bell` names into the `Change` represented by it.
+    /// Converts a string of valid `Bell` names into the `Change` represented by it.  The `Bell`s
+    /// aren't required to form a valid row - use [Change::try_from_str] to check this.
     fn from(s: &str) -> Change {
         let mut change = Change::empty();
 
@@ -769,6 +1467,37 @@ impl From<&str> for Change {
     }
 }
 
+/// The ways in which a string can fail to represent a valid [Change].
+#[derive(Copy, Clone, Hash, Debug, PartialEq, Eq)]
+pub enum InvalidRowError {
+    /// A character that isn't the name of any [Bell].
+    IllegalBell(char),
+    /// A [Bell] that is too large to appear in a row of this length.
+    BellOutOfRange(Bell),
+    /// A [Bell] that appears more than once.
+    RepeatedBell(Bell),
+}
+
+impl fmt::Display for InvalidRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidRowError::IllegalBell(c) => write!(f, "Illegal bell name '{}'", c),
+            InvalidRowError::BellOutOfRange(b) => {
+                write!(f, "Bell '{}' is too large for the row", b.as_char())
+            }
+            InvalidRowError::RepeatedBell(b) => {
+                write!(
+                    f,
+                    "Bell '{}' appears more than once in the row",
+                    b.as_char()
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for InvalidRowError {}
+
 /// A `ChangeAccumulator` is a struct that can be used to repeatedly accumulate [Change]s, without
 /// allocating any memory on the heap every multiplication.  However, since the stage of the
 /// changes aren't known at compile time, there will be two heap allocations when the
@@ -1163,9 +1892,13 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{Bell, Change, Parity, Place, Stage, Transposition};
+    use crate::{
+        closure, Bell, Change, InvalidRowError, Method, NamedRow, Parity, Place, PlaceNotation,
+        Stage, Touch, Transposition,
+    };
 
     use crate::utils::ExtentIterator;
+    use std::collections::BTreeMap;
 
     #[test]
     fn equality() {
@@ -1220,6 +1953,32 @@ mod tests {
         Change::from("2134 ");
     }
 
+    #[test]
+    fn invalid_rows() {
+        for (string, error) in &[
+            ("133", InvalidRowError::RepeatedBell(Bell::from('3'))),
+            ("124", InvalidRowError::BellOutOfRange(Bell::from('4'))),
+            ("136", InvalidRowError::BellOutOfRange(Bell::from('6'))),
+            ("2134 ", InvalidRowError::IllegalBell(' ')),
+        ] {
+            assert_eq!(Change::try_from_str(*string), Err(*error));
+        }
+
+        for string in &["", "1", "132", "321", "1357924680ET"] {
+            assert_eq!(Change::try_from_str(*string), Ok(Change::from(*string)));
+        }
+
+        // Bell names are case-insensitive
+        assert_eq!(
+            Change::try_from_str("1357924680et"),
+            Ok(Change::from("1357924680ET"))
+        );
+        assert_eq!(
+            Change::try_from_str("1234567890eE"),
+            Err(InvalidRowError::RepeatedBell(Bell::from('E')))
+        );
+    }
+
     #[test]
     fn stage() {
         assert_eq!(Stage::from(0), Change::from("").stage());
@@ -1227,6 +1986,42 @@ mod tests {
         assert_eq!(Stage::from(10), Change::from("6789052431").stage());
     }
 
+    #[test]
+    fn ordering() {
+        let mut changes: Vec<Change> = ["13254768", "12345678", "87654321", "12354678", "21436587"]
+            .iter()
+            .map(|s| Change::from(*s))
+            .collect();
+
+        changes.sort();
+
+        assert_eq!(
+            changes,
+            ["12345678", "12354678", "13254768", "21436587", "87654321"]
+                .iter()
+                .map(|s| Change::from(*s))
+                .collect::<Vec<Change>>()
+        );
+    }
+
+    #[test]
+    fn btree_map_keys() {
+        let mut map = BTreeMap::new();
+
+        for (i, row) in ["2143", "1234", "4321", "1243", "123", "12345"]
+            .iter()
+            .enumerate()
+        {
+            map.insert(Change::from(*row), i);
+        }
+
+        assert_eq!(
+            map.keys().map(|c| c.to_string()).collect::<Vec<_>>(),
+            vec!["<123>", "<1234>", "<12345>", "<1243>", "<2143>", "<4321>"]
+        );
+        assert_eq!(map[&Change::from("4321")], 2);
+    }
+
     #[test]
     fn parity() {
         assert_eq!(Parity::Even, Change::from("1234567").parity());
@@ -1289,6 +2084,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pre_and_post_multiplication() {
+        for (a, b) in &[
+            ("1234", "1234"),
+            ("17823456", "43215678"),
+            ("13572468", "21436587"),
+            ("3512746", "7654321"),
+        ] {
+            let a = Change::from(*a);
+            let b = Change::from(*b);
+
+            assert_eq!(a.post_multiply(&b), a.clone() * b.clone());
+            assert_eq!(a.pre_multiply(&b), b.clone() * a.clone());
+            assert_eq!(a.post_multiply(&b), b.pre_multiply(&a));
+
+            // Multiplying by an inverse on the same side undoes the multiplication
+            assert_eq!(a.post_multiply(&b).post_multiply(&b.inverse()), a);
+            assert_eq!(a.pre_multiply(&b).pre_multiply(&b.inverse()), a);
+            assert_eq!(a.post_multiply(&b), a.multiply_inverse(&b.inverse()));
+        }
+    }
+
     #[test]
     fn multiply_into() {
         let mut change = Change::rounds(Stage::MAJOR);
@@ -1389,6 +2206,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn enumerate() {
+        for row in &["12345", "7298324516", "", "0987123456"] {
+            let c = Change::from(*row);
+
+            assert_eq!(c.enumerate().len(), c.stage().as_usize());
+
+            for (p, b) in c.enumerate() {
+                assert_eq!(b, c.bell_at(p));
+            }
+
+            // Iterating in reverse should start from the back
+            let places: Vec<Place> = c.enumerate().rev().map(|(p, _)| p).collect();
+
+            assert_eq!(
+                places,
+                (0..c.stage().as_usize())
+                    .rev()
+                    .map(Place::from)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
     #[test]
     fn cyclicness_tests() {
         assert!(Change::from("12345").is_full_cyclic());
@@ -1413,6 +2254,300 @@ mod tests {
         assert!(!Change::from("42513").is_reverse_fixed_treble_cyclic());
     }
 
+    #[test]
+    fn cyclic_part_ends() {
+        for (stage, fixed, part_end) in &[
+            (Stage::DOUBLES, "1", "13452"),
+            (Stage::TRIPLES, "1", "1345672"),
+            (Stage::MAJOR, "12", "12456783"),
+            (Stage::MAJOR, "178", "13456278"),
+            (Stage::ROYAL, "", "2345678901"),
+            (Stage::MINOR, "123456", "123456"),
+        ] {
+            let fixed: Vec<Bell> = fixed.chars().map(Bell::from).collect();
+            let cyclic = Change::cyclic(*stage, &fixed);
+
+            assert_eq!(cyclic, Change::from(*part_end));
+
+            let moving_bells = stage.as_usize() - fixed.len();
+
+            assert_eq!(closure(&cyclic).len(), moving_bells.max(1));
+        }
+    }
+
+    #[test]
+    fn step() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MAJOR));
+
+        let mut row = Change::rounds(Stage::MAJOR);
+
+        for (i, pn) in plain_bob
+            .place_notations
+            .iter()
+            .cycle()
+            .take(plain_course.length)
+            .enumerate()
+        {
+            assert_eq!(plain_course.row_at(i).slice(), row.slice());
+
+            row.step(pn);
+        }
+
+        assert_eq!(row, plain_course.leftover_change);
+
+        // Odd stages have a bell making a place at the back
+        let mut row = Change::from("12345");
+
+        row.step(&PlaceNotation::from_str("1", Stage::DOUBLES));
+
+        assert_eq!(row, Change::from("13254"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_stage_mismatch() {
+        Change::rounds(Stage::MINOR).step(&PlaceNotation::from_str("x", Stage::MAJOR));
+    }
+
+    #[test]
+    fn get() {
+        for row in &["12345", "7298324516", "", "0987123456"] {
+            let c = Change::from(*row);
+            let stage = c.stage().as_usize();
+
+            for i in 0..stage {
+                assert_eq!(c.get(Place::from(i)), Some(c.bell_at(Place::from(i))));
+            }
+
+            assert_eq!(c.get(Place::from(stage)), None);
+            assert_eq!(c.get(Place::from(100)), None);
+        }
+    }
+
+    #[test]
+    fn cross_applied() {
+        for row in &["123456", "241635", "1234567890ET", "87654321", "21", ""] {
+            let change = Change::from(*row);
+
+            assert_eq!(
+                change.cross_applied(),
+                change.multiply(&PlaceNotation::cross(change.stage()).transposition())
+            );
+            assert_eq!(change.cross_applied().cross_applied(), change);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn cross_applied_odd_stage() {
+        Change::rounds(Stage::DOUBLES).cross_applied();
+    }
+
+    #[test]
+    fn cycles() {
+        for row in &[
+            "12345678", "21436587", "13572468", "87654321", "2345671", "1", "",
+        ] {
+            let change = Change::from(*row);
+            let cycles = change.cycles();
+
+            assert_eq!(
+                cycles.iter().map(|c| c.len()).sum::<usize>(),
+                change.stage().as_usize()
+            );
+            assert_eq!(Change::from_cycles(change.stage(), &cycles), change);
+        }
+
+        assert_eq!(Change::from("2345671").cycles().len(), 1);
+        assert_eq!(Change::from("21436587").cycles().len(), 4);
+        assert_eq!(
+            Change::from("13572468").cycles(),
+            vec![
+                vec![Bell::from('1')],
+                vec![Bell::from('2'), Bell::from('3'), Bell::from('5')],
+                vec![Bell::from('4'), Bell::from('7'), Bell::from('6')],
+                vec![Bell::from('8')],
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_cycles_repeated_bell() {
+        Change::from_cycles(
+            Stage::MINIMUS,
+            &[
+                vec![Bell::from('1'), Bell::from('2')],
+                vec![Bell::from('2'), Bell::from('3'), Bell::from('4')],
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_cycles_missing_bell() {
+        Change::from_cycles(Stage::MINIMUS, &[vec![Bell::from('1'), Bell::from('2')]]);
+    }
+
+    #[test]
+    fn conjugate() {
+        for (change, by, conjugate) in &[
+            ("13425678", "21345678", "32415678"),
+            ("15234", "54321", "23415"),
+            ("123456", "654321", "123456"),
+            ("", "", ""),
+        ] {
+            let change = Change::from(*change);
+            let by = Change::from(*by);
+
+            assert_eq!(change.conjugate(&by), Change::from(*conjugate));
+            assert_eq!(change.conjugate(&by).conjugate(&by.inverse()), change);
+            assert_eq!(change.conjugate(&Change::rounds(change.stage())), change);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn conjugate_stage_mismatch() {
+        Change::from("1342").conjugate(&Change::from("21345"));
+    }
+
+    #[test]
+    fn hunt_steps() {
+        for s in 0..=12 {
+            let stage = Stage::from(s);
+            let rows: Vec<Change> = (0..2 * s).map(|n| Change::hunt_steps(stage, n)).collect();
+
+            // A full hunt cycle comes back to rounds
+            assert!(Change::hunt_steps(stage, 2 * s).is_rounds());
+            assert_eq!(
+                Change::hunt_steps(stage, 2 * s + 3),
+                Change::hunt_steps(stage, 3)
+            );
+
+            // ... but not before then (plain hunt on fewer than 3 bells repeats sooner)
+            if s >= 3 {
+                assert_eq!(rows.iter().filter(|r| r.is_rounds()).count(), 1);
+            }
+        }
+
+        assert_eq!(
+            Change::hunt_steps(Stage::MAJOR, 8),
+            Change::from("87654321")
+        );
+        assert_eq!(
+            Change::hunt_steps(Stage::TRIPLES, 7),
+            Change::from("7654321")
+        );
+    }
+
+    #[test]
+    fn patterns() {
+        for (row, pattern, matches) in &[
+            ("14325678", "1x3xxxxx", true),
+            ("12345678", "1x3xxxxx", true),
+            ("18365472", "1x3xxxxx", true),
+            ("31245678", "1x3xxxxx", false),
+            ("21345678", "1x3xxxxx", false),
+            ("65432178", "xxxxxx78", true),
+            ("65432187", "xxxxxx78", false),
+            ("1234567890ET", "xxxxxxxxxxet", true),
+            ("12345678", "xxxxxxxx", true),
+            ("12345678", "12345678", true),
+            ("12345678", "1x3xxxx", false),
+            ("12345678", "1x3xxxxxx", false),
+            ("12345678", "1x3xxx?x", false),
+            ("", "", true),
+        ] {
+            assert_eq!(Change::from(*row).matches_pattern(pattern), *matches);
+        }
+    }
+
+    #[test]
+    fn stable_hash() {
+        // These values are fixed forever, since hashes may have been persisted
+        for (row, hash) in &[
+            ("", 0xcbf2_9ce4_8422_2325u64),
+            ("1", 0xaf63_bd4c_8601_b7df),
+            ("12345678", 0xa4dc_49e2_b28e_cb7d),
+        ] {
+            let change = Change::from(*row);
+
+            assert_eq!(change.stable_hash(), *hash);
+            assert_eq!(change.stable_hash(), change.clone().stable_hash());
+        }
+
+        // Every row of an extent should get its own hash
+        let mut hashes: Vec<u64> = ExtentIterator::new(Stage::MINOR)
+            .map(|c| c.stable_hash())
+            .collect();
+
+        hashes.sort();
+        hashes.dedup();
+
+        assert_eq!(hashes.len(), 720);
+    }
+
+    #[test]
+    fn standard_rows() {
+        assert_eq!(
+            Change::standard_rows(Stage::MAJOR),
+            vec![
+                (NamedRow::Rounds, Change::from("12345678")),
+                (NamedRow::Backrounds, Change::from("87654321")),
+                (NamedRow::Queens, Change::from("13572468")),
+                (NamedRow::Kings, Change::from("75312468")),
+                (NamedRow::Tittums, Change::from("15263748")),
+                (NamedRow::Whittingtons, Change::from("12753468")),
+            ]
+        );
+
+        // Whittingtons doesn't exist on odd stages
+        assert_eq!(
+            Change::standard_rows(Stage::CATERS)
+                .iter()
+                .map(|(r, _)| *r)
+                .collect::<Vec<_>>(),
+            vec![
+                NamedRow::Rounds,
+                NamedRow::Backrounds,
+                NamedRow::Queens,
+                NamedRow::Kings,
+                NamedRow::Tittums,
+            ]
+        );
+
+        for s in 0..=16 {
+            for (_, row) in Change::standard_rows(Stage::from(s)) {
+                let mut bells = row.slice().to_vec();
+
+                bells.sort();
+
+                assert_eq!(Change::new(bells), Change::rounds(Stage::from(s)));
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_named_rows() {
+        for (row, named_row, distance) in &[
+            ("13572468", NamedRow::Queens, 0),
+            ("13574268", NamedRow::Queens, 2),
+            ("12753468", NamedRow::Whittingtons, 0),
+            ("12753486", NamedRow::Whittingtons, 2),
+            ("15263748", NamedRow::Tittums, 0),
+            ("75312468", NamedRow::Kings, 0),
+            ("87654312", NamedRow::Backrounds, 2),
+            ("1", NamedRow::Rounds, 0),
+        ] {
+            assert_eq!(
+                Change::from(*row).nearest_named_row(),
+                (*named_row, *distance)
+            );
+        }
+    }
+
     #[test]
     fn backrounds_test() {
         assert!(Change::from("4321").is_backrounds());
@@ -1442,6 +2577,13 @@ mod tests {
         assert_eq!(Change::from("14238765").run_length_off_back(), 4);
         assert_eq!(Change::from("76543218").run_length_off_back(), 1);
         assert_eq!(Change::from("1234567890").run_length_off_back(), 10);
+
+        assert_eq!(Change::from("12346578").front_run_length(), 4);
+        assert_eq!(Change::from("12346578").back_run_length(), 2);
+        assert_eq!(Change::from("14238765").front_run_length(), 0);
+        assert_eq!(Change::from("76543218").back_run_length(), 0);
+        assert_eq!(Change::from("12345678").back_run_length(), 8);
+        assert_eq!(Change::from("").front_run_length(), 0);
     }
 
     #[test]
@@ -1523,6 +2665,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn self_reverse() {
+        for (s, is_self_reverse) in &[
+            ("", true),
+            ("1", true),
+            ("21", false),
+            ("14325", false),
+            ("12345678", false),
+            ("87654321", false),
+            ("13245678", false),
+        ] {
+            let change = Change::from(*s);
+
+            assert_eq!(change.is_self_reverse(), *is_self_reverse);
+        }
+    }
+
     #[test]
     fn destructive_hash() {
         for s in 1..=8 {
@@ -1545,6 +2704,63 @@ mod tests {
         assert_eq!(format!("{}", Change::from("14325")), "<14325>");
         assert_eq!(format!("{}", Change::from("1678902345ET")), "<1678902345ET>");
     }
+
+    #[test]
+    fn coursing_order_strings() {
+        for (course_head, stage, expected) in &[
+            ("123456", Stage::MINOR, "53246"),
+            ("12345678", Stage::MAJOR, "7532468"),
+            ("1234", Stage::MAJOR, "7532468"),
+            ("13425678", Stage::MAJOR, "7543268"),
+            ("1234567890", Stage::ROYAL, "975324680"),
+        ] {
+            assert_eq!(
+                Change::from(*course_head).coursing_order_string(*stage),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn coursing_order_string_too_small() {
+        Change::rounds(Stage::MAJOR).coursing_order_string(Stage::MINOR);
+    }
+
+    #[test]
+    fn treble_in_lead() {
+        assert!(Change::rounds(Stage::MINOR).treble_in_lead());
+        assert!(Change::from("1").treble_in_lead());
+        assert!(Change::from("1654327890").treble_in_lead());
+
+        assert!(!Change::from("213456").treble_in_lead());
+        assert!(!Change::from("654321").treble_in_lead());
+        assert!(!Change::empty().treble_in_lead());
+    }
+
+    #[test]
+    fn delimited_strings() {
+        assert_eq!(Change::from("13524").to_delimited(','), "1,3,5,2,4");
+        assert_eq!(
+            Change::from("1234567890ET").to_delimited(' '),
+            "1 2 3 4 5 6 7 8 9 0 E T"
+        );
+        assert_eq!(Change::from("21").to_delimited('\t'), "2\t1");
+        assert_eq!(Change::empty().to_delimited(','), "");
+    }
+
+    #[test]
+    fn to_numbers() {
+        assert_eq!(
+            Change::rounds(Stage::MINOR).to_numbers(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            Change::from("2143658709TE").to_numbers(),
+            vec![2, 1, 4, 3, 6, 5, 8, 7, 10, 9, 12, 11]
+        );
+        assert_eq!(Change::empty().to_numbers(), Vec::<u8>::new());
+    }
 }
 
 #[cfg(test)]
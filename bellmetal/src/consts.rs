@@ -34,7 +34,8 @@ static BELL_NAME_LOOKUP_TABLE: [i8; 91] = [
 ];
 
 /// Given a [char], returns `true` if it is a valid [Bell] name (but without searching through the
-/// entirety of [BELL_NAMES] every time).
+/// entirety of [BELL_NAMES] every time).  Bell names are case-insensitive, so `'e'` is treated the
+/// same as `'E'`.
 ///
 /// # Example
 /// ```
@@ -43,10 +44,13 @@ static BELL_NAME_LOOKUP_TABLE: [i8; 91] = [
 /// assert!(is_bell_name('4'));
 /// assert!(is_bell_name('0'));
 /// assert!(is_bell_name('E'));
+/// assert!(is_bell_name('e'));
 /// assert!(!is_bell_name('I'));
 /// assert!(!is_bell_name(' '));
 /// ```
 pub fn is_bell_name(c: char) -> bool {
+    let c = c.to_ascii_uppercase();
+
     ((c >= '0' && c <= '9') || (c >= 'A' && c <= 'Z'))
         && c != 'I'
         && c != 'O'
@@ -55,8 +59,10 @@ pub fn is_bell_name(c: char) -> bool {
 }
 
 /// Converts a [char] into either a valid [Bell] number or `-1`, even if the [char] points to outside
-/// the range of [BELL_NAME_LOOKUP_TABLE].
+/// the range of [BELL_NAME_LOOKUP_TABLE].  Lowercase names are read as their uppercase versions.
 fn get_number(name: char) -> i8 {
+    let name = name.to_ascii_uppercase();
+
     // Return `-1` if outside the range of [BELL_NAME_LOOKUP_TABLE]
     if name as usize >= BELL_NAME_LOOKUP_TABLE.len() {
         return -1;
@@ -68,7 +74,7 @@ fn get_number(name: char) -> i8 {
 }
 
 /// Convert a [char] representing a [Bell] into the [Number] that represents it (where `0`
-/// represents the treble).
+/// represents the treble).  The name is case-insensitive.
 ///
 /// # Example
 /// ```
@@ -77,6 +83,7 @@ fn get_number(name: char) -> i8 {
 /// assert_eq!(name_to_number('1'), 0);
 /// assert_eq!(name_to_number('4'), 3);
 /// assert_eq!(name_to_number('T'), 11);
+/// assert_eq!(name_to_number('t'), 11);
 /// assert_eq!(name_to_number('0'), 9);
 /// ```
 pub fn name_to_number(name: char) -> Number {
@@ -126,7 +133,7 @@ mod tests {
 
             print!("{}", c);
 
-            assert_eq!(get_from_names(c), get_number(c));
+            assert_eq!(get_from_names(c.to_ascii_uppercase()), get_number(c));
         }
     }
 
@@ -139,6 +146,10 @@ mod tests {
         assert!(!is_bell_name('\n'));
         assert!(!is_bell_name('\0'));
         assert!(!is_bell_name('!'));
+        assert!(is_bell_name('e'));
+        assert!(is_bell_name('t'));
+        assert!(!is_bell_name('x'));
+        assert!(!is_bell_name('i'));
     }
 
     #[test]
@@ -1,3 +1,9 @@
+//! Bells and places are numbered from 0 throughout BellMetal, so `Bell::from(0)` is the treble
+//! and `Place::from(0)` is lead.  Bell names (as used when parsing and displaying rows and place
+//! notation) count from 1 like ringers do, so `Bell::from('1') == Bell::from(0)`.  Use
+//! `Bell::from_number_one_indexed` or `Place::from_number_one_indexed` to convert from the
+//! numbers that ringers use.
+
 #![allow(dead_code)]
 
 pub mod change;
@@ -16,23 +22,34 @@ pub mod types;
 pub mod utils;
 
 // Flatten the module structure for easier importing
-pub use change::{Change, ChangeAccumulator, ChangeCollectIter};
+pub use change::{Change, ChangeAccumulator, ChangeCollectIter, InvalidRowError};
 pub use consts::{is_bell_name, name_to_number, BELL_NAMES, MAX_STAGE};
 pub use coursing_order::{
     first_plain_bob_lead_head, plain_bob_lead_head, BasicCoursingOrderIterator, CoursingOrder,
     CoursingOrderIterator, LeadheadCoursingOrderIterator, PlainCoursingOrderIterator, RunSection,
 };
-pub use method::{Call, Method, HALF_LEAD_LOCATION, LEAD_END_LOCATION};
+pub use method::{
+    calling_position_name, calling_position_place, lead_head_from_code, lead_head_orders, Call,
+    Method, MethodError, CALLING_POSITIONS, HALF_LEAD_LOCATION, LEAD_END_LOCATION,
+};
 pub use method_library::{deserialise_method, serialise_method, MethodLibrary};
-pub use music_scoring::{DefaultScoring, MusicScoring};
-pub use place_notation::PlaceNotation;
+pub use music_scoring::{DefaultScoring, MusicBreakdown, MusicScheme, MusicScoring, NamedRow};
+pub use place_notation::{
+    ParsePlaceNotationError, PlaceNotation, PlaceNotationParseError, PnToken,
+};
 pub use proving::{
-    canon_copy, canon_fixed_treble_cyclic, canon_full_cyclic, CompactHashProver,
+    canon_copy, canon_fixed_treble_cyclic, canon_full_cyclic, prove_all, CompactHashProver,
     FullProvingContext, HashProver, NaiveProver, ProvingContext,
 };
-pub use touch::{BasicTouchIterator, Row, Touch};
-pub use touch_generation::{one_part_spliced_touch, single_method_touch};
+pub use touch::{
+    BasicTouchIterator, CallError, CompactTouch, NotMethodLead, NotRoundBlock, Row,
+    RowStringsError, Touch,
+};
+pub use touch_generation::{
+    all_true_touches, extent_touch, find_calling_for_length, one_part_spliced_touch,
+    reachable_lead_heads, search_touches, single_method_touch,
+};
 pub use touch_iterator::{MultiChainTouchIterator, TouchIterator, TransfiguredTouchIterator};
 pub use transposition::{MultiplicationIterator, Transposition};
 pub use types::{Bell, Mask, MaskMethods, Number, Parity, Place, Stage, Stroke};
-pub use utils::{closure, extent};
+pub use utils::{closure, extent, is_in_plain_course_group};
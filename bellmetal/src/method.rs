@@ -1,10 +1,12 @@
 use crate::{
-    Bell, Change, ChangeAccumulator, MultiplicationIterator, PlaceNotation, Row, Stage, Touch,
-    Transposition,
+    closure, is_in_plain_course_group, Bell, Change, ChangeAccumulator, MultiplicationIterator,
+    MusicBreakdown, MusicScheme, Place, PlaceNotation, Row, Stage, Touch, Transposition,
 };
 
 use common_macros::hash_map;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::error;
+use std::fmt;
 
 pub const LEAD_END_LOCATION: &str = "LE";
 pub const HALF_LEAD_LOCATION: &str = "HL";
@@ -74,8 +76,121 @@ impl Call {
             stage,
         }
     }
+
+    // Finds the lead of the plain course of `method` at the end of which making this call brings
+    // the method's observation bell to the given calling position (see `calling_position_place`).
+    // Returns `None` if `position` isn't a calling position on this stage, or if this call never
+    // brings the observation bell there in the plain course.
+    pub fn at_calling_position(&self, method: &Method, position: char) -> Option<usize> {
+        let place = calling_position_place(position, method.stage)?;
+        let observation_bell = method.observation_bell();
+        // Unlike `lead_head_after_call`, this also handles calls of more than one change
+        let called_lead_head = method
+            .get_lead_fragment_with_calls(0, method.lead_length(), std::iter::once(self))
+            .leftover_change;
+
+        let mut lead_head = Change::rounds(method.stage);
+        let mut lead_index = 0;
+
+        loop {
+            if lead_head
+                .multiply(&called_lead_head)
+                .place_of(observation_bell)
+                == place
+            {
+                return Some(lead_index);
+            }
+
+            lead_head = lead_head.multiply(method.lead_head());
+            lead_index += 1;
+
+            if lead_head.is_rounds() {
+                return None;
+            }
+        }
+    }
+
+    // A call stays in the method's group if every lead head produced by calling it repeatedly is
+    // one of the lead heads of the method's plain course
+    pub fn stays_in_group(&self, method: &Method) -> bool {
+        closure(&method.lead_head_after_call(self))
+            .iter()
+            .all(|lead_head| is_in_plain_course_group(lead_head, method))
+    }
+
+    // The bells whose paths through a lead of `method` (starting from `course_head`) are changed
+    // by making this call in that lead, i.e. the bells that are in a different place in some row
+    // of the called lead (or the lead head after it) than in the plain lead.  The bells are
+    // returned in ascending order.
+    pub fn affected_bells(&self, method: &Method, course_head: &Change) -> Vec<Bell> {
+        assert_eq!(self.stage, method.stage);
+        assert_eq!(course_head.stage(), method.stage);
+
+        let called_lead =
+            method.get_lead_fragment_with_calls(0, method.lead_length(), std::iter::once(self));
+        let plain_lead = &method.plain_lead;
+
+        let mut is_affected = vec![false; method.stage.as_usize()];
+
+        let mut mark_differences = |plain_row: &[Bell], called_row: &[Bell]| {
+            for (a, b) in plain_row.iter().zip(called_row) {
+                if a != b {
+                    is_affected[course_head.bell_at(a.as_place()).as_usize()] = true;
+                    is_affected[course_head.bell_at(b.as_place()).as_usize()] = true;
+                }
+            }
+        };
+
+        for (plain_row, called_row) in plain_lead.row_iterator().zip(called_lead.row_iterator()) {
+            mark_differences(plain_row.slice(), called_row.slice());
+        }
+
+        mark_differences(
+            plain_lead.leftover_change.slice(),
+            called_lead.leftover_change.slice(),
+        );
+
+        is_affected
+            .iter()
+            .enumerate()
+            .filter(|(_, is_affected)| **is_affected)
+            .map(|(b, _)| Bell::from(b))
+            .collect()
+    }
 }
 
+// The ways in which the plain lead of a method can fail to be ringable.  Row indices count
+// from the start of the lead, with the lead head having the index of the lead length.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MethodError {
+    // Row `i` is the same as the row after it
+    RepeatedRow(usize),
+    // The bells of row `i` don't all move by at most one place (swapping with their
+    // neighbours) to reach the row after it
+    IllegalChange(usize),
+    // The treble leads at the lead end, but isn't leading in the lead head
+    HuntBellNotHome,
+}
+
+impl fmt::Display for MethodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MethodError::RepeatedRow(i) => write!(f, "Row {} is repeated in the next row", i),
+            MethodError::IllegalChange(i) => {
+                write!(f, "Row {} can't be changed into the next row", i)
+            }
+            MethodError::HuntBellNotHome => {
+                write!(
+                    f,
+                    "The treble hunts to the lead end but doesn't return home"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for MethodError {}
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct Method {
     pub name: String,
@@ -216,11 +331,212 @@ impl Method {
             name: new_name.to_string(),
             stage: self.stage,
             plain_lead: self.plain_lead.inverted(),
-            place_notations: selfplace_notationsn.iter().map(|x| x.reversed()).collect(),
+            place_notations: self.place_notations.iter().map(|x| x.reversed()).collect(),
             location_map: self.location_map.clone(),
         }
     }
 
+    pub fn reverse(&self) -> Method {
+        self.inverted(&format!("Reverse {}", self.name))
+    }
+
+    pub fn backward(&self) -> Method {
+        let place_notations: Vec<PlaceNotation> =
+            self.place_notations.iter().rev().cloned().collect();
+
+        Method::new(
+            format!("Backward {}", self.name),
+            place_notations,
+            self.location_map.clone(),
+        )
+    }
+
+    // A method is double if the second half of its lead is the first half with every place
+    // notation reversed
+    pub fn is_double(&self) -> bool {
+        let len = self.place_notations.len();
+
+        if len == 0 || len % 2 == 1 {
+            return false;
+        }
+
+        let (first_half, second_half) = self.place_notations.split_at(len / 2);
+
+        first_half
+            .iter()
+            .zip(second_half)
+            .all(|(a, b)| a.reversed() == *b)
+    }
+
+    // A method is little if the treble's path in the plain lead never reaches the back
+    pub fn is_little(&self) -> bool {
+        let back = Place::from(self.stage.as_usize() - 1);
+
+        self.plain_lead
+            .row_iterator()
+            .all(|r| r.place_of(Bell::from(0)) != back)
+    }
+
+    // The bell whose position defines calling positions, which is conventionally the tenor
+    pub fn observation_bell(&self) -> Bell {
+        Bell::from(self.stage.as_usize().saturating_sub(1))
+    }
+
+    // A method is a principle if it has no hunt bells, i.e. its plain lead head moves every bell
+    pub fn is_principle(&self) -> bool {
+        self.lead_head()
+            .slice()
+            .iter()
+            .enumerate()
+            .all(|(i, b)| b.as_usize() != i)
+    }
+
+    // Checks that the plain lead of this method (including the move into the next lead head) is
+    // made of legal changes which never leave the bells where they were, and that the treble
+    // returns home if it hunts to the lead end
+    pub fn is_valid(&self) -> Result<(), MethodError> {
+        let lead = &self.plain_lead;
+        let row = |i: usize| -> &[Bell] {
+            if i == lead.length {
+                lead.leftover_change.slice()
+            } else {
+                lead.slice_at(i)
+            }
+        };
+
+        for i in 0..lead.length {
+            let (a, b) = (row(i), row(i + 1));
+
+            if a == b {
+                return Err(MethodError::RepeatedRow(i));
+            }
+
+            if a.len() != b.len() {
+                return Err(MethodError::IllegalChange(i));
+            }
+
+            let mut p = 0;
+
+            while p < a.len() {
+                if b[p] == a[p] {
+                    p += 1;
+                } else if p + 1 < a.len() && b[p] == a[p + 1] && b[p + 1] == a[p] {
+                    p += 2;
+                } else {
+                    return Err(MethodError::IllegalChange(i));
+                }
+            }
+        }
+
+        let treble = Bell::from(0);
+
+        if lead.length > 0
+            && self.lead_end_slice().first() == Some(&treble)
+            && self.lead_head().slice().first() != Some(&treble)
+        {
+            return Err(MethodError::HuntBellNotHome);
+        }
+
+        Ok(())
+    }
+
+    // Generates the double method whose first half lead is the same as this method's.  Returns
+    // `None` if this method's lead can't be split into halves
+    pub fn double(&self) -> Option<Method> {
+        if self.is_double() {
+            return Some(self.clone());
+        }
+
+        let len = self.place_notations.len();
+
+        if len == 0 || len % 2 == 1 {
+            return None;
+        }
+
+        let first_half = &self.place_notations[..len / 2];
+        let mut place_notations = Vec::with_capacity(len);
+
+        place_notations.extend(first_half);
+        place_notations.extend(first_half.iter().map(|x| x.reversed()));
+
+        Some(Method::new(
+            format!("Double {}", self.name),
+            place_notations,
+            self.location_map.clone(),
+        ))
+    }
+
+    // Finds the course heads (with the treble and tenor fixed) whose courses share rows with the
+    // plain course of this method.  These are sorted and don't include rounds.
+    pub fn false_course_heads(&self) -> Vec<Change> {
+        let stage = self.stage.as_usize();
+        let plain_course = Touch::single_course(self, &Change::rounds(self.stage));
+
+        let rows: Vec<Change> = plain_course
+            .row_iterator()
+            .map(|r| Change::new(r.slice().to_vec()))
+            .collect();
+        let inverse_rows: Vec<Change> = rows.iter().map(|r| r.inverse()).collect();
+
+        let mut course_heads: HashSet<Change> = HashSet::new();
+
+        for r in &rows {
+            for s in &inverse_rows {
+                let course_head = r.multiply(s);
+
+                if course_head.bell_at(Place::from(0)) == Bell::from(0)
+                    && course_head.bell_at(Place::from(stage - 1)) == Bell::from(stage - 1)
+                    && !course_head.is_rounds()
+                {
+                    course_heads.insert(course_head);
+                }
+            }
+        }
+
+        let mut course_heads: Vec<Change> = course_heads.into_iter().collect();
+
+        course_heads.sort();
+
+        course_heads
+    }
+
+    pub fn is_plain_course_true(&self) -> bool {
+        Touch::single_course(self, &Change::rounds(self.stage)).is_true()
+    }
+
+    // The music in the plain course of this method, as counted by `scheme`
+    pub fn plain_course_music(&self, scheme: &MusicScheme) -> MusicBreakdown {
+        Touch::single_course(self, &Change::rounds(self.stage)).music_breakdown(scheme)
+    }
+
+    // Writes out the plain course of this method, one row per line, with each row followed by the
+    // place notation that is rung to produce the next row
+    pub fn to_annotated_grid(&self) -> String {
+        let stage = self.stage.as_usize();
+        let plain_course = Touch::single_course(self, &Change::rounds(self.stage));
+
+        let mut string = String::with_capacity((plain_course.length + 1) * (stage * 2 + 2));
+
+        for (row, pn) in plain_course
+            .row_iterator()
+            .zip(self.place_notations.iter().cycle())
+        {
+            for b in row.slice() {
+                string.push(b.as_char());
+            }
+
+            string.push(' ');
+            pn.write_to_string_full(&mut string);
+            string.push('\n');
+        }
+
+        for b in plain_course.leftover_change.slice() {
+            string.push(b.as_char());
+        }
+
+        string
+    }
+
     pub fn is_lead_end_variant_of(&self, other: &Method) -> bool {
         if self.lead_length() != other.lead_length() {
             return false;
@@ -387,11 +703,124 @@ impl Method {
             hash_map! { LEAD_END_LOCATION.to_string () => l },
         )
     }
+
+    // The method where every bell plain hunts, which has a lead length of twice the stage and
+    // comes round at the first lead end.  On even stages this alternates `x` and `1n`, and on
+    // odd stages it alternates `n` and `1`.
+    pub fn plain_hunt(stage: Stage) -> Method {
+        let n = stage.as_usize();
+
+        let hunt = if n & 1 == 0 {
+            PlaceNotation::cross(stage)
+        } else {
+            PlaceNotation::from_str(&Place::from(n - 1).as_char().to_string(), stage)
+        };
+
+        let name = match stage.name() {
+            Some(stage_name) => format!("Plain Hunt {}", stage_name),
+            None => format!("Plain Hunt on {}", n),
+        };
+
+        Method::new_with_lead_end_location(
+            name,
+            [hunt, PlaceNotation::from_str("1", stage)].repeat(n),
+        )
+    }
+}
+
+// The calling positions, in the order used to name places that more than one of them refers to
+pub const CALLING_POSITIONS: &str = "IOBMWH";
+
+// The place that the observation bell is in at the lead head after a call at `position`: `I`,
+// `O` and `B` are 2nds, 3rds and 4ths, and `M`, `W` and `H` are 3rds, 2nds and 1sts from the back.
+// On low stages some of these are the same place (e.g. `B` and `M` are both 4ths on Minor).
+// Returns `None` if `position` isn't a calling position, or doesn't exist on `stage`.
+pub fn calling_position_place(position: char, stage: Stage) -> Option<Place> {
+    let n = stage.as_usize();
+
+    let place = match position {
+        'I' => 1,
+        'O' => 2,
+        'B' => 3,
+        'M' => n.checked_sub(3)?,
+        'W' => n.checked_sub(2)?,
+        'H' => n.checked_sub(1)?,
+        _ => return None,
+    };
+
+    // The observation bell can never be called into the treble's place
+    if place == 0 || place >= n {
+        return None;
+    }
+
+    Some(Place::from(place))
+}
+
+// The name of the calling position at `place`, or `None` if it isn't one.  When several positions
+// share a place, the first of them in `CALLING_POSITIONS` is used, so 4ths on Minor is `B`, not
+// `M`.
+pub fn calling_position_name(place: Place, stage: Stage) -> Option<char> {
+    CALLING_POSITIONS
+        .chars()
+        .find(|&c| calling_position_place(c, stage) == Some(place))
+}
+
+// Returns the lead head given by a lead head code on an even stage.  These lead heads are the
+// powers of Plain Bob's lead head: `a` and `b` are the first two, and `d`, `e` and `f` are the
+// last three (`c` is only unambiguous on Major).  Codes `g` to `m` (skipping `i`) are used for
+// methods with nth place lead ends, and give the same lead heads as `a` to `f`.  Returns `None`
+// for unknown codes, codes that don't exist on `stage` and odd stages.
+pub fn lead_head_from_code(code: char, stage: Stage) -> Option<Change> {
+    let n = stage.as_usize();
+
+    if n < 6 || n & 1 == 1 {
+        return None;
+    }
+
+    // The number of lead heads in the plain course other than rounds
+    let num_lead_heads = n - 2;
+
+    let power = match code {
+        'a' | 'g' => 1,
+        'b' | 'h' => 2,
+        'c' | 'j' if num_lead_heads == 6 => 3,
+        'd' | 'k' if num_lead_heads > 4 => num_lead_heads - 2,
+        'e' | 'l' => num_lead_heads - 1,
+        'f' | 'm' => num_lead_heads,
+        _ => return None,
+    };
+
+    // Plain Bob's lead is plain hunt, but with 12 instead of the last 1n
+    let mut plain_bob_lead_head = Change::rounds(stage);
+
+    Change::hunt_steps(stage, 2 * n - 1).multiply_iterator_into(
+        PlaceNotation::from_str("12", stage).iter(),
+        &mut plain_bob_lead_head,
+    );
+
+    closure(&plain_bob_lead_head).get(power).cloned()
+}
+
+// Every lead head code that exists on `stage`, along with its lead head and the order of that
+// lead head (i.e. the number of leads in the plain course of a method with that lead head)
+pub fn lead_head_orders(stage: Stage) -> Vec<(char, Change, usize)> {
+    "abcdefghjklm"
+        .chars()
+        .filter_map(|code| lead_head_from_code(code, stage).map(|lh| (code, lh)))
+        .map(|(code, lh)| {
+            let order = closure(&lh).len();
+
+            (code, lh, order)
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod call_tests {
-    use crate::{Call, PlaceNotation, Stage, LEAD_END_LOCATION};
+    use crate::{
+        calling_position_name, calling_position_place, Bell, Call, Change, Method, Place,
+        PlaceNotation, Stage, LEAD_END_LOCATION,
+    };
 
     #[test]
     #[should_panic]
@@ -411,11 +840,146 @@ mod call_tests {
     fn empty_pn() {
         Call::lead_end_call_from_place_notation_string('-', "", Stage::MAJOR);
     }
+
+    #[test]
+    fn calling_positions() {
+        let plain_bob_major = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MAJOR);
+
+        for (call, position, lead_index) in &[
+            (&bob, 'W', Some(0)),
+            (&bob, 'B', Some(2)),
+            (&bob, 'O', Some(3)),
+            (&bob, 'I', Some(4)),
+            (&bob, 'M', Some(5)),
+            (&bob, 'H', Some(6)),
+            // A single leaves the tenor where a bob would
+            (&single, 'H', Some(6)),
+            (&single, 'W', Some(0)),
+            (&bob, 'X', None),
+        ] {
+            assert_eq!(
+                call.at_calling_position(&plain_bob_major, *position),
+                *lead_index
+            );
+        }
+
+        // Grandsire's bob replaces the last two changes of the lead, and never brings the tenor into
+        // 4ths
+        let grandsire = Method::from_str("Grandsire Triples", "3,1.7.1.7.1.7.1", Stage::TRIPLES);
+        let grandsire_bob = Call::new(
+            '-',
+            PlaceNotation::from_multiple_string("3.1", Stage::TRIPLES),
+            LEAD_END_LOCATION,
+        );
+
+        for (position, lead_index) in &[('I', Some(0)), ('W', Some(2)), ('H', Some(3)), ('B', None)]
+        {
+            assert_eq!(
+                grandsire_bob.at_calling_position(&grandsire, *position),
+                *lead_index
+            );
+        }
+    }
+
+    #[test]
+    fn calling_position_places() {
+        for (position, stage, place) in &[
+            ('I', Stage::MAJOR, Some(1)),
+            ('B', Stage::MAJOR, Some(3)),
+            ('M', Stage::MAJOR, Some(5)),
+            ('H', Stage::MAJOR, Some(7)),
+            ('B', Stage::MINOR, Some(3)),
+            ('M', Stage::MINOR, Some(3)),
+            ('W', Stage::TRIPLES, Some(5)),
+            ('B', Stage::MINIMUS, Some(3)),
+            ('M', Stage::MINIMUS, Some(1)),
+            ('M', Stage::SINGLES, None),
+            ('x', Stage::MAJOR, None),
+        ] {
+            assert_eq!(
+                calling_position_place(*position, *stage),
+                place.map(Place::from)
+            );
+        }
+
+        for (place, stage, name) in &[
+            (1, Stage::MAJOR, Some('I')),
+            (4, Stage::MAJOR, None),
+            (5, Stage::MAJOR, Some('M')),
+            (7, Stage::MAJOR, Some('H')),
+            // 4ths is both `B` and `M` on Minor
+            (3, Stage::MINOR, Some('B')),
+            (4, Stage::MINOR, Some('W')),
+            (0, Stage::MINOR, None),
+        ] {
+            assert_eq!(calling_position_name(Place::from(*place), *stage), *name);
+        }
+    }
+
+    #[test]
+    fn stays_in_group() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob_triples =
+            Method::from_str("Plain Bob Triples", "7.1.7.1.7.1.7,127", Stage::TRIPLES);
+        let bob_minimus = Method::from_str("Plain Bob Minimus", "x14x14,12", Stage::MINIMUS);
+
+        for (method, notation, stays_in_group) in &[
+            // Calling the plain lead end only gives plain lead heads
+            (&plain_bob, "12", true),
+            (&bob_triples, "127", true),
+            // These give a lead head of rounds, so the touch comes round straight away
+            (&plain_bob, "18", true),
+            (&bob_minimus, "14", true),
+            // Bobs and singles take the touch into other courses
+            (&plain_bob, "14", false),
+            (&plain_bob, "1234", false),
+            (&bob_minimus, "1234", false),
+            (&bob_triples, "147", false),
+            // Moves the treble away from the lead
+            (&plain_bob, "34", false),
+            (&plain_bob, "x", false),
+            (&bob_triples, "347", false),
+        ] {
+            let call = Call::lead_end_call_from_place_notation_string('-', notation, method.stage);
+
+            assert_eq!(call.stays_in_group(method), *stays_in_group);
+        }
+    }
+
+    #[test]
+    fn affected_bells() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+
+        for (notation, course_head, affected) in &[
+            // The bells in 2nds, 3rds and 4ths at the lead end
+            ("14", "12345678", "235"),
+            ("14", "13425678", "345"),
+            ("14", "12345687", "235"),
+            ("1234", "12345678", "25"),
+            ("18", "12345678", "2345678"),
+            // Calling the plain lead end changes nothing
+            ("12", "12345678", ""),
+        ] {
+            let call = Call::lead_end_call_from_place_notation_string('-', notation, Stage::MAJOR);
+
+            assert_eq!(
+                call.affected_bells(&plain_bob, &Change::from(*course_head)),
+                affected.chars().map(Bell::from).collect::<Vec<_>>()
+            );
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Call, Change, Method, PlaceNotation, Stage, HALF_LEAD_LOCATION};
+    use crate::{
+        lead_head_from_code, lead_head_orders, Bell, Call, Change, Method, MethodError,
+        MusicBreakdown, MusicScheme, Parity, Place, PlaceNotation, Stage, Touch, Transposition,
+        HALF_LEAD_LOCATION,
+    };
+    use std::collections::HashMap;
 
     #[test]
     fn lead_lengths() {
@@ -604,6 +1168,420 @@ mod tests {
         }
     }
 
+    #[test]
+    fn variations() {
+        let double_bob = Method::from_str("Double Bob Minor", "x1x1x56,2", Stage::MINOR);
+        let plain_bob = Method::from_str("Plain Bob Minor", "x1x1x1,2", Stage::MINOR);
+        let cambridge = Method::from_str(
+            "Cambridge Surprise Major",
+            "x38x14x1258x36x14x58x16x78,12",
+            Stage::MAJOR,
+        );
+
+        // Double methods
+        assert!(double_bob.is_double());
+        assert!(!plain_bob.is_double());
+        assert!(!cambridge.is_double());
+
+        assert_eq!(double_bob.double(), Some(double_bob.clone()));
+
+        let double_plain_bob = plain_bob.double().unwrap();
+
+        assert!(double_plain_bob.is_double());
+        assert_eq!(
+            double_plain_bob.place_notations,
+            PlaceNotation::from_multiple_string("x1x1x1x1x1x1", Stage::MINOR)
+        );
+
+        // Reverse and backward
+        for method in &[&double_bob, &plain_bob, &cambridge] {
+            assert_eq!(
+                method.reverse().reverse().place_notations,
+                method.place_notations
+            );
+            assert_eq!(method.reverse().reverse().plain_lead, method.plain_lead);
+            assert_eq!(
+                method.backward().backward().place_notations,
+                method.place_notations
+            );
+        }
+
+        assert_eq!(
+            cambridge.reverse().place_notations,
+            PlaceNotation::from_multiple_string("x16x58x1478x36x58x14x38x12,78", Stage::MAJOR)
+        );
+        assert_eq!(cambridge.reverse().name, "Reverse Cambridge Surprise Major");
+        assert_eq!(
+            plain_bob.backward().place_notations,
+            PlaceNotation::from_multiple_string("12x16x16x16x16x16x", Stage::MINOR)
+        );
+    }
+
+    #[test]
+    fn lead_head_order_tables() {
+        let major = lead_head_orders(Stage::MAJOR);
+
+        assert_eq!(
+            major.iter().map(|(code, _, _)| *code).collect::<String>(),
+            "abcdefghjklm"
+        );
+
+        for (code, lead_head, order) in &major {
+            assert_eq!(
+                lead_head_from_code(*code, Stage::MAJOR).as_ref(),
+                Some(lead_head)
+            );
+            assert_eq!(*order, 7);
+        }
+
+        // `c` only exists on Major, and `d` only exists above Minor
+        assert_eq!(
+            lead_head_orders(Stage::MINOR)
+                .iter()
+                .map(|(code, _, order)| (*code, *order))
+                .collect::<Vec<_>>(),
+            vec![
+                ('a', 5),
+                ('b', 5),
+                ('e', 5),
+                ('f', 5),
+                ('g', 5),
+                ('h', 5),
+                ('l', 5),
+                ('m', 5)
+            ]
+        );
+
+        // On Royal the `d` lead head is the sixth power of a 9-cycle, so only has order 3
+        for (code, _, order) in lead_head_orders(Stage::ROYAL) {
+            assert_eq!(order, if code == 'd' || code == 'k' { 3 } else { 9 });
+        }
+
+        assert!(lead_head_orders(Stage::TRIPLES).is_empty());
+    }
+
+    #[test]
+    fn lead_head_codes() {
+        for (code, stage, lead_head) in &[
+            ('a', Stage::MAJOR, "13527486"),
+            ('b', Stage::MAJOR, "15738264"),
+            ('c', Stage::MAJOR, "17856342"),
+            ('d', Stage::MAJOR, "18674523"),
+            ('e', Stage::MAJOR, "16482735"),
+            ('f', Stage::MAJOR, "14263857"),
+            ('m', Stage::MAJOR, "14263857"),
+            ('a', Stage::MINOR, "135264"),
+            ('f', Stage::MINOR, "142635"),
+            ('b', Stage::MAXIMUS, "157392E4T608"),
+            ('f', Stage::ROYAL, "1426385079"),
+        ] {
+            assert_eq!(
+                lead_head_from_code(*code, *stage),
+                Some(Change::from(*lead_head))
+            );
+        }
+
+        // Yorkshire and Bristol have lead head codes `b` and `m`
+        let yorkshire = Method::from_str(
+            "Yorkshire Surprise Major",
+            "x38x14x58x16x12x38x14x78,12",
+            Stage::MAJOR,
+        );
+        let bristol = Method::from_str(
+            "Bristol Surprise Major",
+            "x58x14.58x58.36.14x14.58x14x18,18",
+            Stage::MAJOR,
+        );
+
+        assert_eq!(
+            lead_head_from_code('b', Stage::MAJOR).as_ref(),
+            Some(yorkshire.lead_head())
+        );
+        assert_eq!(
+            lead_head_from_code('m', Stage::MAJOR).as_ref(),
+            Some(bristol.lead_head())
+        );
+
+        for (code, stage) in &[
+            ('i', Stage::MAJOR),
+            ('z', Stage::MAJOR),
+            ('A', Stage::MAJOR),
+            ('c', Stage::ROYAL),
+            ('d', Stage::MINOR),
+            ('a', Stage::TRIPLES),
+            ('a', Stage::MINIMUS),
+        ] {
+            assert_eq!(lead_head_from_code(*code, *stage), None);
+        }
+    }
+
+    #[test]
+    fn little_methods() {
+        for (name, pn, stage, is_little) in &[
+            ("Little Bob Major", "x18x14,12", Stage::MAJOR, true),
+            ("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR, false),
+            ("Little Bob Minor", "x16x14,12", Stage::MINOR, true),
+            ("Plain Bob Minor", "x16x16x16,12", Stage::MINOR, false),
+            (
+                "Cambridge Surprise Minor",
+                "x36x14x12x36x14x56,12",
+                Stage::MINOR,
+                false,
+            ),
+            ("Plain Bob Doubles", "5.1.5.1.5,125", Stage::DOUBLES, false),
+        ] {
+            assert_eq!(Method::from_str(name, pn, *stage).is_little(), *is_little);
+        }
+    }
+
+    #[test]
+    fn validity() {
+        for (pn, stage, result) in &[
+            ("x16x16x16,12", Stage::MINOR, Ok(())),
+            ("x36x14x12x36x14x56,12", Stage::MINOR, Ok(())),
+            ("3,1.5.1.5.1", Stage::DOUBLES, Ok(())),
+            ("3.1.5.3.1.3.1.3.5.1.3.1", Stage::DOUBLES, Ok(())),
+            (
+                "x.16.123456.x.16",
+                Stage::MINOR,
+                Err(MethodError::RepeatedRow(2)),
+            ),
+            (
+                "x16x16x16,x",
+                Stage::MINOR,
+                Err(MethodError::HuntBellNotHome),
+            ),
+        ] {
+            assert_eq!(Method::from_str("", pn, *stage).is_valid(), *result);
+        }
+
+        // The 3rd jumps from 4ths place to 2nds place in the second change
+        let method = Method {
+            name: String::new(),
+            stage: Stage::MINOR,
+            place_notations: Vec::new(),
+            plain_lead: Touch::from("123456\n214365\n231465\n123456"),
+            location_map: HashMap::new(),
+        };
+
+        assert_eq!(method.is_valid(), Err(MethodError::IllegalChange(1)));
+    }
+
+    #[test]
+    fn plain_hunt() {
+        for (stage, pn, name) in &[
+            (Stage::MINOR, "x16x16x16,16", "Plain Hunt Minor"),
+            (Stage::MAJOR, "x18x18x18x18,18", "Plain Hunt Major"),
+            (Stage::DOUBLES, "5.1.5.1.5,1", "Plain Hunt Doubles"),
+            (Stage::SINGLES, "3.1.3,1", "Plain Hunt Singles"),
+        ] {
+            let method = Method::plain_hunt(*stage);
+            let plain_course = Touch::single_course(&method, &Change::rounds(*stage));
+
+            assert_eq!(method.name, *name);
+            assert_eq!(
+                method.place_notations,
+                PlaceNotation::from_multiple_string(pn, *stage)
+            );
+            assert_eq!(method.lead_length(), stage.as_usize() * 2);
+            assert!(method.lead_head().is_rounds());
+            assert_eq!(plain_course.length, stage.as_usize() * 2);
+            assert!(plain_course.leftover_change.is_rounds());
+            assert!(plain_course.is_true());
+        }
+    }
+
+    #[test]
+    fn observation_bells() {
+        for (name, pn, stage, bell) in &[
+            ("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR, '8'),
+            ("Plain Bob Minor", "x16x16x16,12", Stage::MINOR, '6'),
+            ("Grandsire Triples", "3,1.7.1.7.1.7.1", Stage::TRIPLES, '7'),
+            (
+                "Plain Bob Maximus",
+                "x1Tx1Tx1Tx1Tx1Tx1T,12",
+                Stage::MAXIMUS,
+                'T',
+            ),
+        ] {
+            assert_eq!(
+                Method::from_str(name, pn, *stage).observation_bell(),
+                Bell::from(*bell)
+            );
+        }
+    }
+
+    #[test]
+    fn principles() {
+        for (name, pn, stage, is_principle) in &[
+            ("Original Minor", "x16", Stage::MINOR, true),
+            ("Plain Bob Minor", "x16x16x16,12", Stage::MINOR, false),
+            (
+                "Stedman Doubles",
+                "3.1.5.3.1.3.1.3.5.1.3.1",
+                Stage::DOUBLES,
+                true,
+            ),
+            (
+                "Grandsire Triples",
+                "3,1.7.1.7.1.7.1",
+                Stage::TRIPLES,
+                false,
+            ),
+            // Every bell is fixed by the lead head
+            ("Crossing Minor", "x.x", Stage::MINOR, false),
+        ] {
+            assert_eq!(
+                Method::from_str(name, pn, *stage).is_principle(),
+                *is_principle
+            );
+        }
+    }
+
+    #[test]
+    fn plain_course_truth() {
+        for (name, pn_string, stage, is_true) in &[
+            ("Plain Bob Minor", "x16x16x16,12", Stage::MINOR, true),
+            ("Grandsire Triples", "3,1.7.1.7.1.7.1", Stage::TRIPLES, true),
+            (
+                "Bristol Surprise Major",
+                "x58x14.58x58.36.14x14.58x14x18,18",
+                Stage::MAJOR,
+                true,
+            ),
+            // Every other row is rounds
+            ("Crossing Minor", "x.x.x.x", Stage::MINOR, false),
+            // Plain hunts over the same 12 rows twice in every lead
+            (
+                "Double Hunt Minor",
+                "x16x16x16x16x16x16,12",
+                Stage::MINOR,
+                false,
+            ),
+        ] {
+            assert_eq!(
+                Method::from_str(name, pn_string, *stage).is_plain_course_true(),
+                *is_true
+            );
+        }
+    }
+
+    #[test]
+    fn plain_course_music() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let scheme = MusicScheme {
+            four_bell_run: 1,
+            five_bell_run: 2,
+            named_row: 10,
+            cru: 3,
+            wrap: 20,
+        };
+
+        assert_eq!(
+            plain_bob.plain_course_music(&scheme),
+            MusicBreakdown {
+                four_bell_runs: 18,
+                five_bell_runs: 14,
+                named_rows: 1, // Backrounds
+                crus: 4,
+                wraps: 0,
+                score: 18 + 28 + 10 + 12,
+            }
+        );
+    }
+
+    #[test]
+    fn annotated_grid() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let grid = plain_bob.to_annotated_grid();
+
+        assert!(grid.starts_with("123456 x\n214365 16\n241635 x\n"));
+        assert!(grid.ends_with("124365 12\n123456"));
+
+        for (pn_string, stage) in &[
+            ("x16x16x16,12", Stage::MINOR),
+            ("x38x14x1258x36x14x58x16x78,12", Stage::MAJOR),
+            ("3,1.5.1.5.1", Stage::DOUBLES),
+        ] {
+            let stage = *stage;
+            let method = Method::from_str("Test", pn_string, stage);
+            let grid = method.to_annotated_grid();
+
+            let lines: Vec<&str> = grid.lines().collect();
+            let plain_course = Touch::single_course(&method, &Change::rounds(stage));
+
+            assert_eq!(lines.len(), plain_course.length + 1);
+
+            // The annotation column should just be the method's notation, repeated for every lead
+            for (i, line) in lines[..plain_course.length].iter().enumerate() {
+                let mut columns = line.split(' ');
+
+                assert_eq!(
+                    Change::from(columns.next().unwrap()),
+                    Change::new(plain_course.slice_at(i).to_vec())
+                );
+                assert_eq!(
+                    PlaceNotation::from_str(columns.next().unwrap(), stage),
+                    method.place_notations[i % method.lead_length()]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn false_course_heads() {
+        // The plain course of Plain Bob is only repeated by the course with every pair of bells
+        // swapped (which runs the same rows in a different order)
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+
+        assert_eq!(
+            plain_bob.false_course_heads(),
+            vec![Change::from("13254768")]
+        );
+
+        // Bristol's falseness is all in courses with the tenors parted
+        let bristol = Method::from_str(
+            "Bristol Surprise Major",
+            "x58x14.58x58.36.14x14.58x14x18,18",
+            Stage::MAJOR,
+        );
+
+        assert_eq!(
+            bristol.false_course_heads(),
+            [
+                "12435768", "12456738", "12734568", "13254768", "13524678", "13547628", "13625478",
+                "14253678", "14265378", "15342768", "15374268", "16352748", "16745238", "17243658",
+                "17654328",
+            ]
+            .iter()
+            .map(|s| Change::from(*s))
+            .collect::<Vec<_>>()
+        );
+
+        // Cambridge is false in many tenors-together courses
+        let cambridge = Method::from_str(
+            "Cambridge Surprise Major",
+            "x38x14x1258x36x14x58x16x78,12",
+            Stage::MAJOR,
+        );
+
+        assert_eq!(
+            cambridge
+                .false_course_heads()
+                .into_iter()
+                .filter(|c| c.bell_at(Place::from(6)) == Bell::from(6))
+                .collect::<Vec<_>>(),
+            [
+                "12365478", "12435678", "12436578", "12463578", "12536478", "12543678", "13245678",
+                "13246578", "13254678", "13642578", "14325678", "14326578", "14356278", "14625378",
+                "15246378", "15342678", "16324578", "16345278",
+            ]
+            .iter()
+            .map(|s| Change::from(*s))
+            .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn inversion() {
         for (pns, lh) in &[
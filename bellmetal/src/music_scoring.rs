@@ -1,4 +1,4 @@
-use crate::{Bell, Mask, MaskMethods, Number, Touch, Transposition};
+use crate::{Bell, Change, Mask, MaskMethods, Number, Stage, Touch, Transposition};
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum WrapType {
@@ -197,9 +197,222 @@ impl MusicScoring for FullCyclicScoring {
     }
 }
 
+// Rows which are considered musical in their own right, regardless of runs
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum NamedRow {
+    Rounds,
+    Backrounds,
+    Queens,
+    Kings,
+    Tittums,
+    Whittingtons,
+}
+
+impl NamedRow {
+    pub const ALL: [NamedRow; 6] = [
+        NamedRow::Rounds,
+        NamedRow::Backrounds,
+        NamedRow::Queens,
+        NamedRow::Kings,
+        NamedRow::Tittums,
+        NamedRow::Whittingtons,
+    ];
+
+    // Generates this named row on a given stage, or `None` if the row isn't defined on that stage
+    pub fn change(&self, stage: Stage) -> Option<Change> {
+        let n = stage.as_usize();
+
+        let bells: Vec<usize> = match self {
+            NamedRow::Rounds => (0..n).collect(),
+            NamedRow::Backrounds => (0..n).rev().collect(),
+            NamedRow::Queens if n >= 4 => (0..n).step_by(2).chain((1..n).step_by(2)).collect(),
+            NamedRow::Kings if n >= 4 => (0..n).step_by(2).rev().chain((1..n).step_by(2)).collect(),
+            NamedRow::Tittums if n >= 4 => {
+                let half = n - n / 2;
+
+                let mut bells = Vec::with_capacity(n);
+
+                for i in 0..half {
+                    bells.push(i);
+
+                    if half + i < n {
+                        bells.push(half + i);
+                    }
+                }
+
+                bells
+            }
+            NamedRow::Whittingtons if n >= 6 && n & 1 == 0 => {
+                let b = n - 6;
+
+                (0..b)
+                    .chain(vec![b + 4, b + 2, b, b + 1, b + 3, b + 5])
+                    .collect()
+            }
+            _ => {
+                return None;
+            }
+        };
+
+        Some(Change::from_iterator(bells.into_iter().map(Bell::from)))
+    }
+}
+
+// Weights used to score a touch by counting different types of music.  Unlike the types that
+// implement [MusicScoring], these can be configured at runtime.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MusicScheme {
+    pub four_bell_run: i64,
+    pub five_bell_run: i64,
+    pub named_row: i64,
+    pub cru: i64,
+    pub wrap: i64,
+}
+
+// The number of each type of music in a touch.  Runs are counted at each end of every row, and a
+// run of 5 bells also counts as a 4-bell run.  Named rows don't include rounds.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MusicBreakdown {
+    pub four_bell_runs: usize,
+    pub five_bell_runs: usize,
+    pub named_rows: usize,
+    pub crus: usize,
+    pub wraps: usize,
+    pub score: i64,
+}
+
+// Combination roll-ups are rows ending with two of the bells 4, 5 and 6 (or the equivalent on
+// higher stages) followed by the two heaviest bells in order, e.g. `xxxx4678` in Major
+fn is_cru(slice: &[Bell]) -> bool {
+    let n = slice.len();
+
+    if n < 6 {
+        return false;
+    }
+
+    let is_combination_bell = |b: Bell| b.as_usize() + 5 >= n && b.as_usize() + 3 <= n;
+
+    slice[n - 1].as_usize() == n - 1
+        && slice[n - 2].as_usize() == n - 2
+        && is_combination_bell(slice[n - 3])
+        && is_combination_bell(slice[n - 4])
+}
+
+// Counts how many times `target` is found split over the boundary between two consecutive rows
+pub(crate) fn count_wraps_of(touch: &Touch, target: &Change) -> usize {
+    let stage = touch.stage.as_usize();
+    let target = target.slice();
+
+    (1..touch.length)
+        .filter(|&i| {
+            let first = touch.slice_at(i - 1);
+            let second = touch.slice_at(i);
+
+            (1..stage).any(|split| {
+                first[split..] == target[..stage - split]
+                    && second[..split] == target[stage - split..]
+            })
+        })
+        .count()
+}
+
+impl MusicScheme {
+    // A simple ready-made scheme for when there's no reason to pick particular weights.  This
+    // isn't any published standard: every 4-bell run scores 1 (so a 5-bell run, which also counts
+    // as a 4-bell run, scores 2), each named row and combination roll-up scores 2, and wrapped
+    // rounds score nothing.  The 56s and 65s (rows ending `5678` or `6578` in Major) are CRUs, so
+    // are scored as such.
+    pub fn basic() -> MusicScheme {
+        MusicScheme {
+            four_bell_run: 1,
+            five_bell_run: 1,
+            named_row: 2,
+            cru: 2,
+            wrap: 0,
+        }
+    }
+
+    pub fn score_breakdown(&self, breakdown: &MusicBreakdown) -> i64 {
+        breakdown.four_bell_runs as i64 * self.four_bell_run
+            + breakdown.five_bell_runs as i64 * self.five_bell_run
+            + breakdown.named_rows as i64 * self.named_row
+            + breakdown.crus as i64 * self.cru
+            + breakdown.wraps as i64 * self.wrap
+    }
+
+    pub fn breakdown(&self, touch: &Touch) -> MusicBreakdown {
+        let named_rows: Vec<Change> = NamedRow::ALL
+            .iter()
+            .filter(|r| **r != NamedRow::Rounds)
+            .filter_map(|r| r.change(touch.stage))
+            .collect();
+
+        let mut breakdown = MusicBreakdown::default();
+
+        for row in touch.row_iterator() {
+            for run_length in &[row.run_length_off_front(), row.run_length_off_back()] {
+                if *run_length >= 4 {
+                    breakdown.four_bell_runs += 1;
+                }
+
+                if *run_length >= 5 {
+                    breakdown.five_bell_runs += 1;
+                }
+            }
+
+            if named_rows.iter().any(|r| r.slice() == row.slice()) {
+                breakdown.named_rows += 1;
+            }
+
+            if is_cru(row.slice()) {
+                breakdown.crus += 1;
+            }
+        }
+
+        breakdown.wraps = count_wraps_of(touch, &Change::rounds(touch.stage));
+        breakdown.score = self.score_breakdown(&breakdown);
+
+        breakdown
+    }
+
+    // Scores a touch directly, without building a breakdown
+    pub fn score_touch(&self, touch: &Touch) -> i64 {
+        let named_rows: Vec<Change> = NamedRow::ALL
+            .iter()
+            .filter(|r| **r != NamedRow::Rounds)
+            .filter_map(|r| r.change(touch.stage))
+            .collect();
+
+        let run_score = |length: usize| -> i64 {
+            match length {
+                0..=3 => 0,
+                4 => self.four_bell_run,
+                _ => self.four_bell_run + self.five_bell_run,
+            }
+        };
+
+        let mut score = 0;
+
+        for row in touch.row_iterator() {
+            score += run_score(row.run_length_off_front()) + run_score(row.run_length_off_back());
+
+            if named_rows.iter().any(|r| r.slice() == row.slice()) {
+                score += self.named_row;
+            }
+
+            if is_cru(row.slice()) {
+                score += self.cru;
+            }
+        }
+
+        score + count_wraps_of(touch, &Change::rounds(touch.stage)) as i64 * self.wrap
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Change, DefaultScoring, Transposition};
+    use crate::music_scoring::{MusicBreakdown, MusicScheme, NamedRow};
+    use crate::{Change, DefaultScoring, Stage, Touch, Transposition};
 
     #[test]
     fn music_scoring() {
@@ -228,4 +441,92 @@ mod tests {
             56
         );
     }
+
+    #[test]
+    fn high_bell_runs() {
+        // Runs are found with the bells' internal numbers, so `90ET` is as much a run as `1234`
+        for (row, front, back) in &[
+            ("7890123456", 4, 6),
+            ("1234560987", 6, 4),
+            ("2341567890", 3, 6),
+            ("12345678TE09", 8, 4),
+            ("90ET12345678", 4, 8),
+            ("0912345678", 2, 8),
+        ] {
+            let change = Change::from(*row);
+
+            assert_eq!(change.run_length_off_front(), *front);
+            assert_eq!(change.run_length_off_back(), *back);
+        }
+
+        assert_eq!(Change::from("2143657890").back_run_length(), 4);
+        assert_eq!(Change::from("0987615432").front_run_length(), 5);
+    }
+
+    #[test]
+    fn named_rows() {
+        for (row, stage, expected) in &[
+            (NamedRow::Queens, Stage::MAJOR, Some("13572468")),
+            (NamedRow::Kings, Stage::MAJOR, Some("75312468")),
+            (NamedRow::Tittums, Stage::MAJOR, Some("15263748")),
+            (NamedRow::Whittingtons, Stage::MAJOR, Some("12753468")),
+            (NamedRow::Whittingtons, Stage::ROYAL, Some("1234975680")),
+            (NamedRow::Queens, Stage::TRIPLES, Some("1357246")),
+            (NamedRow::Tittums, Stage::TRIPLES, Some("1526374")),
+            (NamedRow::Backrounds, Stage::MINOR, Some("654321")),
+            (NamedRow::Whittingtons, Stage::TRIPLES, None),
+        ] {
+            assert_eq!(row.change(*stage), expected.map(Change::from));
+        }
+    }
+
+    #[test]
+    fn music_breakdown() {
+        let touch =
+            Touch::from("12345678\n21436587\n13572468\n12354678\n87612345\n67821435\n12345678");
+
+        let scheme = MusicScheme {
+            four_bell_run: 1,
+            five_bell_run: 2,
+            named_row: 10,
+            cru: 3,
+            wrap: 20,
+        };
+
+        let breakdown = touch.music_breakdown(&scheme);
+
+        assert_eq!(
+            breakdown,
+            MusicBreakdown {
+                four_bell_runs: 3, // Both ends of rounds and 54321 in 87612345
+                five_bell_runs: 3, // The same three runs
+                named_rows: 1,     // Queens
+                crus: 2,           // Rounds and 12354678
+                wraps: 1,          // 87612345 -> 67821435 contains wrapped rounds
+                score: 3 + 6 + 10 + 6 + 20,
+            }
+        );
+
+        assert_eq!(breakdown.score, scheme.score_touch(&touch));
+
+        // The same touch under the basic preset
+        let basic = MusicScheme::basic();
+
+        assert_eq!(touch.music_breakdown(&basic).score, 3 + 3 + 2 + 4);
+        assert_eq!(basic.score_touch(&touch), 3 + 3 + 2 + 4);
+
+        // With only runs scoring, the scheme agrees with the default scoring on runs of up to 5
+        // bells
+        let runs_only = MusicScheme {
+            four_bell_run: 1,
+            five_bell_run: 2,
+            ..MusicScheme::default()
+        };
+        let touch = Touch::from("12348765\n12345867\n21436587\n12345678");
+
+        assert_eq!(
+            touch.music_breakdown(&runs_only).score,
+            touch.music_score::<DefaultScoring>() as i64
+        );
+    }
 }
@@ -1,7 +1,9 @@
 use crate::consts;
 use crate::types::*;
-use crate::{Change, ChangeAccumulator, MaskMethods};
+use crate::{closure, Change, ChangeAccumulator, MaskMethods, Method};
+use std::error;
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct PlaceNotation {
@@ -94,13 +96,11 @@ impl PlaceNotation {
             }
         }
 
+        // If there are internal places then 1sts and nths are always implicit.  Otherwise, one of
+        // them has to be written so that the notation isn't mistaken for a cross.
         if count == 0 {
             string.push('x');
-        } else {
-            if internal_place_count > 0 {
-                return;
-            }
-
+        } else if internal_place_count == 0 {
             if is_1sts_made {
                 string.push(Bell::from(0).as_char());
             } else if is_nths_made {
@@ -109,6 +109,14 @@ impl PlaceNotation {
         }
     }
 
+    pub fn minimal_string(&self) -> String {
+        let mut string = String::with_capacity(self.stage.as_usize());
+
+        self.write_to_string_compact(&mut string);
+
+        string
+    }
+
     pub fn write_to_string_full(&self, string: &mut String) {
         let mut count = 0;
 
@@ -124,9 +132,23 @@ impl PlaceNotation {
             string.push('x');
         }
     }
+
+    // Writes the place notation along with its stage (e.g. "1@7" for 1sts made in Triples), so that
+    // it can be read back unambiguously with `PlaceNotation::from_str_with_stage`
+    pub fn to_string_with_stage(&self) -> String {
+        let mut string = String::with_capacity(Mask::limit() as usize);
+
+        self.write_to_string_full(&mut string);
+        string.push('@');
+        string.push_str(&self.stage.as_usize().to_string());
+
+        string
+    }
 }
 
 impl PlaceNotation {
+    // `X` is never a bell name (see `is_bell_name`), so it is always read as a cross, along with
+    // `x` and `-`
     pub fn is_cross_notation(notation: char) -> bool {
         notation == 'X' || notation == 'x' || notation == '-'
     }
@@ -142,19 +164,117 @@ impl PlaceNotation {
         }
     }
 
+    // Returns every place notation on `stage` in which all of `bells` make places (starting from
+    // rounds, so each bell makes its home place), sorted.  The notation in which no bells move
+    // isn't a change, so is never included.
+    pub fn notations_fixing(bells: &[Bell], stage: Stage) -> Vec<PlaceNotation> {
+        fn add_notations(
+            places: Mask,
+            next_place: usize,
+            fixed: Mask,
+            stage: Stage,
+            notations: &mut Vec<PlaceNotation>,
+        ) {
+            let n = stage.as_usize();
+
+            if next_place >= n {
+                notations.push(PlaceNotation { places, stage });
+
+                return;
+            }
+
+            // Either make a place here...
+            let mut with_place = places;
+
+            with_place.add(next_place as Number);
+            add_notations(with_place, next_place + 1, fixed, stage, notations);
+
+            // ... or swap this bell with the next one, if neither of them have to stay put
+            if next_place + 1 < n
+                && !fixed.get(next_place as Number)
+                && !fixed.get((next_place + 1) as Number)
+            {
+                add_notations(places, next_place + 2, fixed, stage, notations);
+            }
+        }
+
+        let mut fixed = Mask::empty();
+
+        for b in bells {
+            fixed.add(b.as_number());
+        }
+
+        let mut notations = Vec::new();
+
+        add_notations(Mask::empty(), 0, fixed, stage, &mut notations);
+
+        notations.retain(|pn| pn.places_made().count() < stage.as_usize());
+        notations.sort();
+
+        notations
+    }
+
     pub fn from_str(notation: &str, stage: Stage) -> PlaceNotation {
+        match PlaceNotation::try_from_str(notation, stage) {
+            Ok(pn) => pn,
+            Err(e) => panic!("Invalid place notation '{}': {}", notation, e),
+        }
+    }
+
+    // Parses a single place notation in the same way as `from_str`, but returns an error
+    // (including the byte offset of the offending character) instead of panicking if the
+    // notation is invalid
+    pub fn try_from_str(
+        notation: &str,
+        stage: Stage,
+    ) -> Result<PlaceNotation, PlaceNotationParseError> {
         let mut places = Mask::empty();
 
-        if notation == "" || notation == "X" || notation == "x" || notation == "-" {
+        let mut chars = notation.chars();
+        let is_cross = match (chars.next(), chars.next()) {
+            // An empty notation is read as a cross
+            (None, _) => {
+                if stage.as_u32() & 1u32 != 0 {
+                    return Err(PlaceNotationParseError::EmptyOnOddStage);
+                }
+
+                true
+            }
+            (Some(c), None) => PlaceNotation::is_cross_notation(c),
+            _ => false,
+        };
+
+        if is_cross {
             if stage.as_u32() & 1u32 != 0 {
-                panic!("Non-even stage used with a cross notation");
+                return Err(PlaceNotationParseError::CrossOnOddStage { offset: 0 });
             }
 
         // Nothing to be done here, since places defaults to 0
         } else {
-            // Should decode bell names as places
-            for c in notation.chars() {
-                places.add(consts::name_to_number(c));
+            for (offset, c) in notation.char_indices() {
+                // A cross can't be mixed with places (otherwise the cross symbol would be misread
+                // as a bell name)
+                if PlaceNotation::is_cross_notation(c) {
+                    return Err(PlaceNotationParseError::CrossWithPlaces { offset });
+                }
+
+                // Should decode bell names as places
+                if !consts::is_bell_name(c) {
+                    return Err(PlaceNotationParseError::UnknownBellName { name: c, offset });
+                }
+
+                let place = consts::name_to_number(c);
+
+                // Make sure that none of the places are above the stage
+                if place >= stage.as_number() {
+                    return Err(PlaceNotationParseError::PlaceAboveStage {
+                        place,
+                        stage,
+                        offset,
+                    });
+                }
+
+                places.add(place);
             }
 
             // Add implicit places (lower place)
@@ -180,7 +300,21 @@ impl PlaceNotation {
             }
         }
 
-        PlaceNotation { places, stage }
+        Ok(PlaceNotation { places, stage })
+    }
+
+    pub fn from_str_with_stage(string: &str) -> PlaceNotation {
+        let (notation, stage) = match string.rfind('@') {
+            Some(i) => (&string[..i], &string[i + 1..]),
+            None => panic!("Place notation '{}' has no stage", string),
+        };
+
+        let stage = match stage.parse::<usize>() {
+            Ok(s) => Stage::from(s),
+            Err(_) => panic!("Invalid stage '{}'", stage),
+        };
+
+        PlaceNotation::from_str(notation, stage)
     }
 
     pub fn notations_to_string_short(place_notations: &[PlaceNotation]) -> String {
@@ -318,39 +452,115 @@ impl PlaceNotation {
         }
     }
 
+    // Splits a block of place notation into the tokens that `from_multiple_string` reads, without
+    // checking that the places are valid for any stage
+    pub fn tokenize(string: &str) -> impl Iterator<Item = PnToken<'_>> {
+        PlaceNotation::tokenize_with_offsets(string).map(|(_, token)| token)
+    }
+
+    // Like `tokenize`, but also gives the byte offset of the start of each token in `string`
+    fn tokenize_with_offsets(string: &str) -> PnTokenIterator<'_> {
+        PnTokenIterator { string, offset: 0 }
+    }
+
     pub fn from_multiple_string(string: &str, stage: Stage) -> Vec<PlaceNotation> {
-        let mut string_buff = String::with_capacity(Mask::limit() as usize);
+        PlaceNotation::parse_block_with_symmetry(string, stage).0
+    }
+
+    // Parses a block of place notation in the same way as `from_multiple_string`, but returns an
+    // error instead of panicking if the block is invalid (e.g. if it contains bracket groups)
+    pub fn try_from_multiple_string(
+        string: &str,
+        stage: Stage,
+    ) -> Result<Vec<PlaceNotation>, PlaceNotationParseError> {
+        Ok(PlaceNotation::try_parse_block_with_symmetry(string, stage)?.0)
+    }
+
+    // Returns `true` if two strings parse to the same block of place notation on `stage`, so
+    // differences such as implicit places, `x` vs `-` or a comma vs writing out the whole block are
+    // ignored
+    pub fn strings_equivalent(a: &str, b: &str, stage: Stage) -> bool {
+        PlaceNotation::from_multiple_string(a, stage)
+            == PlaceNotation::from_multiple_string(b, stage)
+    }
+
+    // Parses a block of place notation in the same way as `from_multiple_string`, but also returns
+    // the index of the comma within the expanded block (in the form used by
+    // `write_notations_to_string_compact`) if the string contained one.  A section starting with
+    // `+` (as used by CompLib) is asymmetric, so isn't reflected even if there is a comma; blocks
+    // with such a section don't have a comma index.
+    pub fn parse_block_with_symmetry(
+        string: &str,
+        stage: Stage,
+    ) -> (Vec<PlaceNotation>, Option<usize>) {
+        match PlaceNotation::try_parse_block_with_symmetry(string, stage) {
+            Ok(block) => block,
+            Err(e) => panic!("Invalid place notation '{}': {}", string, e),
+        }
+    }
+
+    // Parses a block of place notation in the same way as `parse_block_with_symmetry`, but returns
+    // an error (with the byte offset of the problem in `string`) instead of panicking if the block
+    // is invalid
+    pub fn try_parse_block_with_symmetry(
+        string: &str,
+        stage: Stage,
+    ) -> Result<(Vec<PlaceNotation>, Option<usize>), PlaceNotationParseError> {
         let mut place_notations: Vec<PlaceNotation> = Vec::with_capacity(string.len());
         let mut comma_index: Option<usize> = None;
+        let mut is_asymmetric = [false, false];
+
+        for (offset, token) in PlaceNotation::tokenize_with_offsets(string) {
+            match token {
+                PnToken::Cross => {
+                    if stage.as_u32() & 1u32 != 0 {
+                        return Err(PlaceNotationParseError::CrossOnOddStage { offset });
+                    }
 
-        macro_rules! add_place_not {
-            () => {
-                if string_buff.len() != 0 {
-                    place_notations.push(PlaceNotation::from_str(&string_buff, stage));
-                    string_buff.clear();
+                    place_notations.push(PlaceNotation::cross(stage));
                 }
-            };
+                PnToken::Places(places) => place_notations.push(
+                    PlaceNotation::try_from_str(places, stage).map_err(|e| e.shifted_by(offset))?,
+                ),
+                PnToken::Comma => comma_index = Some(place_notations.len()),
+                PnToken::Plus => {
+                    let section_start = comma_index.unwrap_or(0);
+
+                    if place_notations.len() != section_start {
+                        return Err(PlaceNotationParseError::PlusInsideSection { offset });
+                    }
+
+                    is_asymmetric[comma_index.is_some() as usize] = true;
+                }
+                PnToken::Separator => {}
+                PnToken::BracketGroup(group) => {
+                    return Err(PlaceNotationParseError::UnsupportedBracketGroup {
+                        group: group.to_string(),
+                        offset,
+                    })
+                }
+            }
         }
 
-        for c in string.chars() {
-            if c == '.' || c == ' ' {
-                add_place_not!();
-            } else if c == ',' {
-                add_place_not!();
+        // Deal with strings with asymmetric sections, where only the sections that aren't marked
+        // as asymmetric are reflected
+        if let (Some(ind), true) = (comma_index, is_asymmetric[0] || is_asymmetric[1]) {
+            let mut expanded_place_notations: Vec<PlaceNotation> =
+                Vec::with_capacity(place_notations.len() * 2);
 
-                comma_index = Some(place_notations.len());
-            } else if PlaceNotation::is_cross_notation(c) {
-                if !string_buff.is_empty() {
-                    add_place_not!();
-                }
+            for (section, is_asymmetric) in [&place_notations[..ind], &place_notations[ind..]]
+                .iter()
+                .zip(is_asymmetric.iter())
+            {
+                expanded_place_notations.extend(section.iter());
 
-                place_notations.push(PlaceNotation::cross(stage));
-            } else {
-                string_buff.push(c);
+                if !is_asymmetric {
+                    expanded_place_notations.extend(section.iter().rev().skip(1));
+                }
             }
-        }
 
-        add_place_not!();
+            return Ok((expanded_place_notations, None));
+        }
 
         // Deal with strings with comma in them
         if let Some(ind) = comma_index {
@@ -358,7 +568,7 @@ impl PlaceNotation {
             // and so despite clippy's continued complaints, I'm keeping it.
             if false {
                 // The notations before the comma forwards
-                place_notations
+                let reordered_place_notations = place_notations
                     .iter()
                     .take(ind)
                     // The notations before the comma backwards
@@ -369,7 +579,9 @@ impl PlaceNotation {
                     .chain(place_notations.iter().skip(ind).rev().skip(1))
                     // Cloned and put into a vector
                     .cloned()
-                    .collect::<Vec<PlaceNotation>>()
+                    .collect::<Vec<PlaceNotation>>();
+
+                Ok((reordered_place_notations, Some(ind * 2 - 1)))
             } else {
                 let mut reordered_place_notations: Vec<PlaceNotation> =
                     Vec::with_capacity(ind * 2 + (place_notations.len() - ind) * 2 - 2);
@@ -400,11 +612,27 @@ impl PlaceNotation {
                     add!(place_notations.len() - 2 - i);
                 }
 
-                reordered_place_notations
+                Ok((reordered_place_notations, Some(ind * 2 - 1)))
             }
         } else {
-            place_notations
+            Ok((place_notations, None))
+        }
+    }
+
+    // Each place notation swaps every pair of bells that aren't making places, so its parity is
+    // just the parity of the number of those pairs
+    pub fn block_parity(pns: &[PlaceNotation]) -> Parity {
+        let mut parity = Parity::Even;
+
+        for pn in pns {
+            let swaps = (pn.stage.as_usize() - pn.places_made().count()) / 2;
+
+            if swaps & 1 == 1 {
+                parity = !parity;
+            }
         }
+
+        parity
     }
 
     pub fn overall_transposition(pns: &[PlaceNotation]) -> Change {
@@ -420,6 +648,39 @@ impl PlaceNotation {
 
         accum.total().clone()
     }
+
+    // The order of the lead head generated by a lead of place notation, i.e. the number of leads
+    // in the plain course
+    pub fn lead_order(pns: &[PlaceNotation]) -> usize {
+        closure(&PlaceNotation::overall_transposition(pns)).len()
+    }
+
+    // The indices at which two equal-length blocks of place notation differ
+    pub fn diff(a: &[PlaceNotation], b: &[PlaceNotation]) -> Vec<usize> {
+        if a.len() != b.len() {
+            panic!("Can't diff blocks of place notation of different lengths");
+        }
+
+        a.iter()
+            .zip(b.iter())
+            .enumerate()
+            .filter(|(_, (x, y))| x != y)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    // The transposition made by a plain lead of each method.  Methods can only be spliced at the
+    // lead end without changing the lead heads if their transpositions are in the same group.
+    pub fn lead_end_transpositions(methods: &[Method]) -> Vec<Change> {
+        methods.iter().map(|m| m.lead_head().clone()).collect()
+    }
+
+    // The place made at the lead end above the treble's lead (e.g. 2nds for Plain Bob, or the
+    // back place for an nths-place method), or None if the lead end has no such place (e.g. if
+    // it is a cross)
+    pub fn lead_end_place(pns: &[PlaceNotation]) -> Option<Place> {
+        pns.last()?.places_made().find(|p| p.as_number() > 0)
+    }
 }
 
 impl fmt::Display for PlaceNotation {
@@ -432,6 +693,157 @@ impl fmt::Display for PlaceNotation {
     }
 }
 
+// Parses place notation with its stage written before a colon, such as `8:x` or `6:16`.  Unlike
+// `PlaceNotation::from_str`, this returns an error rather than panicking if the notation is
+// invalid.
+impl FromStr for PlaceNotation {
+    type Err = ParsePlaceNotationError;
+
+    fn from_str(string: &str) -> Result<PlaceNotation, Self::Err> {
+        let (stage, notation) = match string.find(':') {
+            Some(i) => (&string[..i], &string[i + 1..]),
+            None => return Err(ParsePlaceNotationError::MissingStage),
+        };
+
+        let stage = match stage.parse::<Number>() {
+            Ok(s) if s > 0 && s <= Mask::limit() => Stage::from(s),
+            _ => return Err(ParsePlaceNotationError::InvalidStage(stage.to_string())),
+        };
+
+        if notation.is_empty() {
+            return Err(ParsePlaceNotationError::MissingNotation);
+        }
+
+        PlaceNotation::try_from_str(notation, stage)
+            .map_err(ParsePlaceNotationError::InvalidNotation)
+    }
+}
+
+// The ways that parsing a `PlaceNotation` with `FromStr` can fail.  Invalid notation after the
+// colon gives the error from `PlaceNotation::try_from_str`, whose byte offsets are counted from
+// the start of the notation.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ParsePlaceNotationError {
+    MissingStage,
+    InvalidStage(String),
+    MissingNotation,
+    InvalidNotation(PlaceNotationParseError),
+}
+
+impl fmt::Display for ParsePlaceNotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePlaceNotationError::MissingStage => {
+                write!(f, "Place notation has no stage before a ':'")
+            }
+            ParsePlaceNotationError::InvalidStage(s) => write!(f, "Invalid stage '{}'", s),
+            ParsePlaceNotationError::MissingNotation => {
+                write!(f, "Place notation has nothing after the ':'")
+            }
+            ParsePlaceNotationError::InvalidNotation(e) => {
+                write!(f, "Invalid place notation: {}", e)
+            }
+        }
+    }
+}
+
+impl error::Error for ParsePlaceNotationError {}
+
+// The ways that place notation can fail to parse with `PlaceNotation::try_from_str` or
+// `PlaceNotation::try_from_multiple_string`.  Each variant (other than `EmptyOnOddStage`, which has
+// no characters to point to) holds the byte offset of the offending character in the notation.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PlaceNotationParseError {
+    EmptyOnOddStage,
+    CrossOnOddStage {
+        offset: usize,
+    },
+    CrossWithPlaces {
+        offset: usize,
+    },
+    UnknownBellName {
+        name: char,
+        offset: usize,
+    },
+    PlaceAboveStage {
+        place: Number,
+        stage: Stage,
+        offset: usize,
+    },
+    PlusInsideSection {
+        offset: usize,
+    },
+    UnsupportedBracketGroup {
+        group: String,
+        offset: usize,
+    },
+}
+
+impl PlaceNotationParseError {
+    // The same error, but with its offset moved `extra` bytes along (for errors found in part of a
+    // larger string)
+    fn shifted_by(mut self, extra: usize) -> PlaceNotationParseError {
+        match &mut self {
+            PlaceNotationParseError::EmptyOnOddStage => {}
+            PlaceNotationParseError::CrossOnOddStage { offset }
+            | PlaceNotationParseError::CrossWithPlaces { offset }
+            | PlaceNotationParseError::UnknownBellName { offset, .. }
+            | PlaceNotationParseError::PlaceAboveStage { offset, .. }
+            | PlaceNotationParseError::PlusInsideSection { offset }
+            | PlaceNotationParseError::UnsupportedBracketGroup { offset, .. } => *offset += extra,
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for PlaceNotationParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlaceNotationParseError::EmptyOnOddStage => {
+                write!(
+                    f,
+                    "Empty place notation (read as a cross) used with a non-even stage"
+                )
+            }
+            PlaceNotationParseError::CrossOnOddStage { .. } => {
+                write!(f, "Non-even stage used with a cross notation")
+            }
+            PlaceNotationParseError::CrossWithPlaces { offset } => write!(
+                f,
+                "Cross notation can't be combined with places (at byte {})",
+                offset
+            ),
+            PlaceNotationParseError::UnknownBellName { name, offset } => {
+                write!(f, "Unknown bell name '{}' (at byte {})", name, offset)
+            }
+            PlaceNotationParseError::PlaceAboveStage {
+                place,
+                stage,
+                offset,
+            } => write!(
+                f,
+                "Place {} is above the stage {} (at byte {})",
+                Place::from(*place as usize).as_char(),
+                stage,
+                offset
+            ),
+            PlaceNotationParseError::PlusInsideSection { offset } => write!(
+                f,
+                "'+' can only be used at the start of a section (at byte {})",
+                offset
+            ),
+            PlaceNotationParseError::UnsupportedBracketGroup { group, offset } => write!(
+                f,
+                "Unsupported bracket group '{}' (at byte {})",
+                group, offset
+            ),
+        }
+    }
+}
+
+impl error::Error for PlaceNotationParseError {}
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct PlaceNotationIterator<'a> {
     place_notation: &'a PlaceNotation,
@@ -481,9 +893,82 @@ impl<'a> Iterator for PlaceNotationIterator<'a> {
     }
 }
 
+// A piece of a block of place notation, as produced by `PlaceNotation::tokenize`
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PnToken<'a> {
+    Cross,
+    Places(&'a str),
+    Comma,
+    Plus,
+    Separator,
+    // A group in square brackets (e.g. the jump changes in `x.[12]`), including the brackets.  These
+    // aren't supported by the parser.
+    BracketGroup(&'a str),
+}
+
+struct PnTokenIterator<'a> {
+    string: &'a str,
+    // The byte offset of `string` in the string being tokenized
+    offset: usize,
+}
+
+impl<'a> Iterator for PnTokenIterator<'a> {
+    type Item = (usize, PnToken<'a>);
+
+    fn next(&mut self) -> Option<(usize, PnToken<'a>)> {
+        let c = self.string.chars().next()?;
+
+        let is_token_end = |c: char| {
+            c == '.'
+                || c == ','
+                || c == '+'
+                || c == '['
+                || c == ']'
+                || c.is_whitespace()
+                || PlaceNotation::is_cross_notation(c)
+        };
+
+        let (token, length) = if c == '.' || c.is_whitespace() {
+            (PnToken::Separator, c.len_utf8())
+        } else if c == ',' {
+            (PnToken::Comma, 1)
+        } else if c == '+' {
+            (PnToken::Plus, 1)
+        } else if c == ']' {
+            (PnToken::BracketGroup(&self.string[..1]), 1)
+        } else if c == '[' {
+            // A group runs up to and including its closing bracket, or up to the next opening
+            // bracket (or the end of the string) if it isn't closed
+            let length = match self.string[1..].find(&['[', ']'][..]) {
+                Some(i) if self.string[i + 1..].starts_with(']') => i + 2,
+                Some(i) => i + 1,
+                None => self.string.len(),
+            };
+
+            (PnToken::BracketGroup(&self.string[..length]), length)
+        } else if PlaceNotation::is_cross_notation(c) {
+            (PnToken::Cross, 1)
+        } else {
+            let length = self.string.find(is_token_end).unwrap_or(self.string.len());
+
+            (PnToken::Places(&self.string[..length]), length)
+        };
+
+        let offset = self.offset;
+
+        self.string = &self.string[length..];
+        self.offset += length;
+
+        Some((offset, token))
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
-    use crate::{Change, ChangeAccumulator, PlaceNotation, Stage, Touch};
+    use crate::{
+        closure, Bell, Change, ChangeAccumulator, MaskMethods, Method, ParsePlaceNotationError,
+        Place, PlaceNotation, PlaceNotationParseError, PnToken, Stage, Touch, Transposition,
+    };
 
     #[test]
     fn is_cross() {
@@ -526,6 +1011,125 @@ pub mod tests {
         PlaceNotation::cross(Stage::CINQUES);
     }
 
+    #[test]
+    fn cross_symbols() {
+        for notation in &["X", "x", "-", ""] {
+            assert!(PlaceNotation::from_str(notation, Stage::MAJOR).is_cross());
+        }
+    }
+
+    #[test]
+    fn notations_fixing() {
+        let treble_fixed = PlaceNotation::notations_fixing(&[Bell::from('1')], Stage::MINOR);
+        let mut expected: Vec<PlaceNotation> = ["12", "14", "16", "1234", "1236", "1256", "1456"]
+            .iter()
+            .map(|pn| PlaceNotation::from_str(pn, Stage::MINOR))
+            .collect();
+
+        expected.sort();
+
+        assert_eq!(treble_fixed, expected);
+
+        // With nothing fixed, every change is allowed (including the cross)
+        let all = PlaceNotation::notations_fixing(&[], Stage::MINOR);
+
+        assert_eq!(all.len(), 12);
+        assert!(all.contains(&PlaceNotation::cross(Stage::MINOR)));
+
+        for stage in 1..=8 {
+            for pn in PlaceNotation::notations_fixing(&[Bell::from(0)], Stage::from(stage)) {
+                assert!(pn.places.get(0));
+            }
+        }
+
+        // Both bells at the front fixed on an odd stage
+        let front_fixed =
+            PlaceNotation::notations_fixing(&[Bell::from('1'), Bell::from('2')], Stage::TRIPLES);
+        let mut expected: Vec<PlaceNotation> =
+            ["127", "125", "123", "12567", "12367", "12347", "12345"]
+                .iter()
+                .map(|pn| PlaceNotation::from_str(pn, Stage::TRIPLES))
+                .collect();
+
+        expected.sort();
+
+        assert_eq!(front_fixed, expected);
+    }
+
+    #[test]
+    fn tokenize() {
+        assert_eq!(
+            PlaceNotation::tokenize("x.18,12").collect::<Vec<_>>(),
+            vec![
+                PnToken::Cross,
+                PnToken::Separator,
+                PnToken::Places("18"),
+                PnToken::Comma,
+                PnToken::Places("12")
+            ]
+        );
+        assert_eq!(
+            PlaceNotation::tokenize("-14x 36").collect::<Vec<_>>(),
+            vec![
+                PnToken::Cross,
+                PnToken::Places("14"),
+                PnToken::Cross,
+                PnToken::Separator,
+                PnToken::Places("36")
+            ]
+        );
+        assert_eq!(
+            PlaceNotation::tokenize("+5.1,+125").collect::<Vec<_>>(),
+            vec![
+                PnToken::Plus,
+                PnToken::Places("5"),
+                PnToken::Separator,
+                PnToken::Places("1"),
+                PnToken::Comma,
+                PnToken::Plus,
+                PnToken::Places("125")
+            ]
+        );
+        assert_eq!(PlaceNotation::tokenize("").next(), None);
+    }
+
+    #[test]
+    fn lowercase_bell_names() {
+        for (lower, upper) in &[("1e", "1E"), ("e", "E"), ("30e", "30E")] {
+            assert_eq!(
+                PlaceNotation::from_str(lower, Stage::CINQUES),
+                PlaceNotation::from_str(upper, Stage::CINQUES)
+            );
+        }
+
+        assert_eq!(
+            PlaceNotation::from_multiple_string("3.1.e.1.e", Stage::CINQUES),
+            PlaceNotation::from_multiple_string("3.1.E.1.E", Stage::CINQUES)
+        );
+        assert_eq!(
+            PlaceNotation::from_multiple_string("x1tx1t", Stage::MAXIMUS),
+            PlaceNotation::from_multiple_string("x1Tx1T", Stage::MAXIMUS)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn cross_before_places() {
+        PlaceNotation::from_str("X1", Stage::MAJOR);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cross_after_places() {
+        PlaceNotation::from_str("1X", Stage::MAJOR);
+    }
+
+    #[test]
+    #[should_panic]
+    fn place_above_stage() {
+        PlaceNotation::from_str("18", Stage::MINOR);
+    }
+
     #[test]
     fn multiple_string_conversion_long() {
         for (input, stage, expansion) in &[
@@ -588,6 +1192,239 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn multiple_string_separators() {
+        for (messy, clean, stage) in &[
+            ("x . 18 \n x18", "x.18.x18", Stage::MAJOR),
+            ("..x..18..", "x.18", Stage::MAJOR),
+            ("\t3 .1\t.5\r\n.1 ,\n 2", "3.1.5.1,2", Stage::DOUBLES),
+            ("  ", "", Stage::MINOR),
+        ] {
+            assert_eq!(
+                PlaceNotation::from_multiple_string(messy, *stage),
+                PlaceNotation::from_multiple_string(clean, *stage)
+            );
+        }
+    }
+
+    #[test]
+    fn block_symmetry() {
+        for (input, stage, comma_index) in &[
+            ("3,1.E.1.E.1.E.1.E.1.E.1", Stage::CINQUES, Some(1)), // Grandsire Cinques
+            ("x1", Stage::MINOR, None),                           // Original Minor
+            ("x2,1", Stage::MINOR, Some(3)),                      // Bastow Minor
+            ("x4x4x7x7x7.36.7.8x,2", Stage::ROYAL, Some(27)), // Hurricane Jack Differential Royal
+        ] {
+            let (pns, index) = PlaceNotation::parse_block_with_symmetry(input, *stage);
+
+            assert_eq!(pns, PlaceNotation::from_multiple_string(input, *stage));
+            assert_eq!(index, *comma_index);
+        }
+
+        // The comma position should be enough to rebuild the compact form
+        let (pns, index) =
+            PlaceNotation::parse_block_with_symmetry("3,1.E.1.E.1.E.1.E.1.E.1", Stage::CINQUES);
+        let index = index.unwrap();
+
+        let mut compact = String::new();
+
+        PlaceNotation::write_notations_to_string_compact(&pns[..index / 2 + 1], &mut compact);
+        compact.push(',');
+        PlaceNotation::write_notations_to_string_compact(
+            &pns[index..index + (pns.len() - index) / 2 + 1],
+            &mut compact,
+        );
+
+        assert_eq!(compact, "3,1.E.1.E.1.E.1.E.1.E.1");
+    }
+
+    #[test]
+    fn asymmetric_sections() {
+        let parse = |s: &str| PlaceNotation::from_multiple_string(s, Stage::MINOR);
+
+        // The section after the comma isn't reflected
+        assert_eq!(parse("x14,+56.12"), parse("x.14.x.56.12"));
+        assert_eq!(parse("x14,56.12"), parse("x.14.x.56.12.56"));
+        // ... nor the section before it
+        assert_eq!(parse("+x14,56.12"), parse("x.14.56.12.56"));
+        assert_eq!(parse("+x14,+56.12"), parse("x.14.56.12"));
+        // Without a comma, a `+` changes nothing
+        assert_eq!(parse("+x16x16x16x12"), parse("x16x16x16x12"));
+
+        // Asymmetric sections don't have a comma position
+        assert_eq!(
+            PlaceNotation::parse_block_with_symmetry("x14,+56.12", Stage::MINOR).1,
+            None
+        );
+
+        // Grandsire Doubles, with the plain lead written out in full
+        assert_eq!(
+            PlaceNotation::from_multiple_string("+3.1.5.1.5.1.5.1.5.1", Stage::DOUBLES),
+            PlaceNotation::from_multiple_string("3,1.5.1.5.1", Stage::DOUBLES)
+        );
+    }
+
+    #[test]
+    fn bracket_tokens() {
+        for (input, group) in &[
+            ("x.[12]", "[12]"),
+            ("x[12]x", "[12]"),
+            ("x.[12", "[12"),
+            ("x.12]", "]"),
+            ("[1[2]", "[1"),
+        ] {
+            assert!(PlaceNotation::tokenize(input).any(|t| t == PnToken::BracketGroup(group)));
+        }
+
+        assert_eq!(
+            PlaceNotation::tokenize("x.[12].14").collect::<Vec<_>>(),
+            vec![
+                PnToken::Cross,
+                PnToken::Separator,
+                PnToken::BracketGroup("[12]"),
+                PnToken::Separator,
+                PnToken::Places("14")
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported bracket group '[12]'")]
+    fn bracket_group() {
+        PlaceNotation::from_multiple_string("x.[12]", Stage::MINOR);
+    }
+
+    #[test]
+    fn try_from_multiple_string() {
+        assert_eq!(
+            PlaceNotation::try_from_multiple_string("x16x16x16,12", Stage::MINOR),
+            Ok(PlaceNotation::from_multiple_string(
+                "x16x16x16,12",
+                Stage::MINOR
+            ))
+        );
+
+        for (notation, stage, err) in &[
+            (
+                "x.[12]",
+                Stage::MINOR,
+                PlaceNotationParseError::UnsupportedBracketGroup {
+                    group: String::from("[12]"),
+                    offset: 2,
+                },
+            ),
+            (
+                "x.12]",
+                Stage::MINOR,
+                PlaceNotationParseError::UnsupportedBracketGroup {
+                    group: String::from("]"),
+                    offset: 4,
+                },
+            ),
+            (
+                "x14+x12",
+                Stage::MINOR,
+                PlaceNotationParseError::PlusInsideSection { offset: 3 },
+            ),
+            (
+                "3.1.x",
+                Stage::DOUBLES,
+                PlaceNotationParseError::CrossOnOddStage { offset: 4 },
+            ),
+            // Offsets are counted from the start of the whole block
+            (
+                "x16x1?",
+                Stage::MINOR,
+                PlaceNotationParseError::UnknownBellName {
+                    name: '?',
+                    offset: 5,
+                },
+            ),
+            (
+                "x18,12",
+                Stage::MINOR,
+                PlaceNotationParseError::PlaceAboveStage {
+                    place: 7,
+                    stage: Stage::MINOR,
+                    offset: 2,
+                },
+            ),
+        ] {
+            assert_eq!(
+                PlaceNotation::try_from_multiple_string(notation, *stage).as_ref(),
+                Err(err)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn plus_inside_section() {
+        PlaceNotation::from_multiple_string("x14+x12", Stage::MINOR);
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(
+            PlaceNotation::try_from_str("45", Stage::MAJOR),
+            Ok(PlaceNotation::from_str("1458", Stage::MAJOR))
+        );
+        assert_eq!(
+            PlaceNotation::try_from_str("x", Stage::MINOR),
+            Ok(PlaceNotation::cross(Stage::MINOR))
+        );
+
+        for (notation, stage, err) in &[
+            (
+                "x",
+                Stage::TRIPLES,
+                PlaceNotationParseError::CrossOnOddStage { offset: 0 },
+            ),
+            ("", Stage::DOUBLES, PlaceNotationParseError::EmptyOnOddStage),
+            (
+                "14x",
+                Stage::MAJOR,
+                PlaceNotationParseError::CrossWithPlaces { offset: 2 },
+            ),
+            (
+                "1?",
+                Stage::MAJOR,
+                PlaceNotationParseError::UnknownBellName {
+                    name: '?',
+                    offset: 1,
+                },
+            ),
+            (
+                "1\u{e9}4",
+                Stage::MAJOR,
+                PlaceNotationParseError::UnknownBellName {
+                    name: '\u{e9}',
+                    offset: 1,
+                },
+            ),
+            (
+                "149",
+                Stage::MAJOR,
+                PlaceNotationParseError::PlaceAboveStage {
+                    place: 8,
+                    stage: Stage::MAJOR,
+                    offset: 2,
+                },
+            ),
+        ] {
+            assert_eq!(
+                PlaceNotation::try_from_str(notation, *stage).as_ref(),
+                Err(err)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown bell name '?'")]
+    fn from_str_unknown_bell() {
+        PlaceNotation::from_str("1?", Stage::MAJOR);
+    }
+
     #[test]
     fn single_string_conversions() {
         for (pn, stage, exp) in &[
@@ -604,6 +1441,221 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn string_conversions_with_stage() {
+        for (pn, stage, exp) in &[
+            ("x", Stage::MAJOR, "x@8"),
+            ("1", Stage::TRIPLES, "1@7"),
+            ("1", Stage::SINGLES, "1@3"),
+            ("45", Stage::MAJOR, "1458@8"),
+            ("1T", Stage::MAXIMUS, "1T@12"),
+        ] {
+            let place_notation = PlaceNotation::from_str(pn, *stage);
+            let string = place_notation.to_string_with_stage();
+
+            assert_eq!(string, *exp);
+            assert_eq!(PlaceNotation::from_str_with_stage(&string), place_notation);
+        }
+    }
+
+    #[test]
+    fn parse() {
+        for (string, pn, stage) in &[
+            ("8:x", "x", Stage::MAJOR),
+            ("6:16", "16", Stage::MINOR),
+            ("8:45", "1458", Stage::MAJOR),
+            ("7:3", "3", Stage::TRIPLES),
+            ("12:-", "x", Stage::MAXIMUS),
+            ("12:1t", "1T", Stage::MAXIMUS),
+        ] {
+            assert_eq!(
+                string.parse::<PlaceNotation>(),
+                Ok(PlaceNotation::from_str(pn, *stage))
+            );
+        }
+
+        for (string, err) in &[
+            ("x", ParsePlaceNotationError::MissingStage),
+            ("", ParsePlaceNotationError::MissingStage),
+            (
+                "a:x",
+                ParsePlaceNotationError::InvalidStage(String::from("a")),
+            ),
+            (":x", ParsePlaceNotationError::InvalidStage(String::new())),
+            (
+                "0:x",
+                ParsePlaceNotationError::InvalidStage(String::from("0")),
+            ),
+            (
+                "5:x",
+                ParsePlaceNotationError::InvalidNotation(
+                    PlaceNotationParseError::CrossOnOddStage { offset: 0 },
+                ),
+            ),
+            (
+                "6:18",
+                ParsePlaceNotationError::InvalidNotation(
+                    PlaceNotationParseError::PlaceAboveStage {
+                        place: 7,
+                        stage: Stage::MINOR,
+                        offset: 1,
+                    },
+                ),
+            ),
+            (
+                "6:1x",
+                ParsePlaceNotationError::InvalidNotation(
+                    PlaceNotationParseError::CrossWithPlaces { offset: 1 },
+                ),
+            ),
+            ("6:", ParsePlaceNotationError::MissingNotation),
+            ("5:", ParsePlaceNotationError::MissingNotation),
+        ] {
+            assert_eq!(string.parse::<PlaceNotation>().as_ref(), Err(err));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_with_stage_missing_stage() {
+        PlaceNotation::from_str_with_stage("14");
+    }
+
+    #[test]
+    fn block_parity() {
+        for (string, stage) in &[
+            ("x", Stage::MAJOR),
+            ("x", Stage::MINOR),
+            ("x4x4x7x7x7.36.7.8x,2", Stage::ROYAL), // Hurricane Jack Differential Royal
+            ("3.4.5.1.5.1.5.1.5.1", Stage::DOUBLES), // Gnu Bob Doubles
+            ("x2,1", Stage::MINOR),                 // Bastow Minor
+            ("3,1.E.1.E.1.E.1.E.1.E.1", Stage::CINQUES), // Grandsire Cinques
+            ("x3x4x25x36x47x58x69x70x8x9x0xE,2", Stage::MAXIMUS), // Cambridge Surprise Maximus
+        ] {
+            let pns = PlaceNotation::from_multiple_string(string, *stage);
+
+            assert_eq!(
+                PlaceNotation::block_parity(&pns),
+                PlaceNotation::overall_transposition(&pns).parity()
+            );
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let cambridge =
+            PlaceNotation::from_multiple_string("x38x14x1258x36x14x58x16x78,12", Stage::MAJOR);
+        let yorkshire =
+            PlaceNotation::from_multiple_string("x38x14x58x16x12x38x14x78,12", Stage::MAJOR);
+        let yorkshire_18 =
+            PlaceNotation::from_multiple_string("x38x14x58x16x12x38x14x78,18", Stage::MAJOR);
+
+        assert!(PlaceNotation::diff(&cambridge, &cambridge).is_empty());
+        assert_eq!(
+            PlaceNotation::diff(&cambridge, &yorkshire),
+            vec![5, 7, 9, 11, 13, 17, 19, 21, 23, 25]
+        );
+        assert_eq!(PlaceNotation::diff(&yorkshire, &yorkshire_18), vec![31]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn diff_different_lengths() {
+        PlaceNotation::diff(
+            &PlaceNotation::from_multiple_string("x16x16x16,12", Stage::MINOR),
+            &PlaceNotation::from_multiple_string("x16x16x16x16,12", Stage::MINOR),
+        );
+    }
+
+    #[test]
+    fn lead_end_transpositions() {
+        let methods: Vec<Method> = [
+            ("Plain Bob Minor", "x16x16x16,12"),
+            ("Cambridge Surprise Minor", "x36x14x12x36x14x56,12"),
+            ("Bastow Little Bob Minor", "x2,1"),
+            ("Primrose Surprise Minor", "x36x14x12x36x14x56,16"),
+            ("Differential Minor", "x14x16,16"),
+        ]
+        .iter()
+        .map(|(name, pn)| Method::from_str(name, pn, Stage::MINOR))
+        .collect();
+
+        let transpositions = PlaceNotation::lead_end_transpositions(&methods);
+
+        assert_eq!(
+            transpositions,
+            ["135264", "156342", "142635", "135264", "623541"]
+                .iter()
+                .map(|s| Change::from(*s))
+                .collect::<Vec<_>>()
+        );
+
+        // Group the methods by the group generated by their lead end transposition
+        let mut groups: Vec<(Vec<Change>, Vec<&str>)> = Vec::new();
+
+        for (method, transposition) in methods.iter().zip(transpositions.iter()) {
+            let mut group = closure(transposition);
+
+            group.sort();
+
+            match groups.iter_mut().find(|(g, _)| *g == group) {
+                Some((_, names)) => names.push(&method.name),
+                None => groups.push((group, vec![&method.name])),
+            }
+        }
+
+        assert_eq!(
+            groups
+                .iter()
+                .map(|(_, names)| names.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    "Plain Bob Minor",
+                    "Cambridge Surprise Minor",
+                    "Bastow Little Bob Minor",
+                    "Primrose Surprise Minor"
+                ],
+                vec!["Differential Minor"]
+            ]
+        );
+    }
+
+    #[test]
+    fn lead_end_places() {
+        for (string, stage, exp) in &[
+            ("x16x16x16,12", Stage::MINOR, Some(1)),    // Plain Bob Minor
+            ("x18x18x18x18,18", Stage::MAJOR, Some(7)), // Nths-place Plain Bob Major
+            ("x58x14.58x58.36.14x14.58x14x18,18", Stage::MAJOR, Some(7)), // Bristol Surprise Major
+            ("5.1.5.1.5,125", Stage::DOUBLES, Some(1)), // Plain Bob Doubles
+            ("x16x16x16,x", Stage::MINOR, None),
+            ("3,1.5.1.5.1", Stage::DOUBLES, None), // Grandsire Doubles
+        ] {
+            assert_eq!(
+                PlaceNotation::lead_end_place(&PlaceNotation::from_multiple_string(string, *stage)),
+                exp.map(Place::from)
+            );
+        }
+
+        assert_eq!(PlaceNotation::lead_end_place(&[]), None);
+    }
+
+    #[test]
+    fn lead_orders() {
+        for (pns, stage, order) in &[
+            ("x16x16x16,12", Stage::MINOR, 5),    // Plain Bob Minor
+            ("3,1.5.1.5.1", Stage::DOUBLES, 3),   // Grandsire Doubles
+            ("x18x18x18x18,12", Stage::MAJOR, 7), // Plain Bob Major
+            ("x58x14.58x58.36.14x14.58x14x18,18", Stage::MAJOR, 7), // Bristol Surprise Major
+            ("x1x1,2", Stage::MINIMUS, 3),        // Plain Bob Minimus
+        ] {
+            assert_eq!(
+                PlaceNotation::lead_order(&PlaceNotation::from_multiple_string(pns, *stage)),
+                *order
+            );
+        }
+    }
+
     #[test]
     #[should_panic]
     fn overall_transposition_empty_panic() {
@@ -685,6 +1737,30 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn equivalent_strings() {
+        for (lhs, rhs, stage, are_equivalent) in &[
+            ("4", "147", Stage::TRIPLES, true),
+            ("47", "147", Stage::CATERS, true),
+            ("45", "1458", Stage::MAJOR, true),
+            ("1", "10", Stage::ROYAL, true),
+            ("14", "1458", Stage::MAJOR, false),
+            (
+                "x18x18x18x18,12",
+                "-18-18-18-18-18-18-18-12",
+                Stage::MAJOR,
+                true,
+            ),
+            ("x16x16x16,12", "x16x16x16,16", Stage::MINOR, false),
+            ("", "", Stage::MINOR, true),
+        ] {
+            assert_eq!(
+                PlaceNotation::strings_equivalent(lhs, rhs, *stage),
+                *are_equivalent
+            );
+        }
+    }
+
     #[test]
     fn transpositions() {
         for (lhs, rhs) in &[
@@ -728,6 +1804,52 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn minimal_strings() {
+        for (from, stage, to) in &[
+            ("145678", Stage::MAJOR, "4567"),
+            ("1458", Stage::MAJOR, "45"),
+            ("1478", Stage::MAJOR, "47"),
+            ("3458", Stage::MAJOR, "345"),
+            ("1234", Stage::MAJOR, "234"),
+            ("18", Stage::MAJOR, "1"),
+            ("1", Stage::TRIPLES, "1"),
+            ("7", Stage::TRIPLES, "7"),
+            ("x", Stage::ROYAL, "x"),
+            ("1256", Stage::MAXIMUS, "256"),
+        ] {
+            let pn = PlaceNotation::from_str(from, *stage);
+            let minimal = pn.minimal_string();
+
+            assert_eq!(minimal, *to);
+            assert_eq!(PlaceNotation::from_str(&minimal, *stage), pn);
+        }
+    }
+
+    #[test]
+    fn minimal_string_round_trip() {
+        // Check every legal place notation (including the one where every bell makes a place)
+        // on every stage up to Maximus
+        for n in 1..=12 {
+            let stage = Stage::from(n);
+            let all_places: String = (0..n).map(|i| Place::from(i).as_char()).collect();
+
+            let mut notations = PlaceNotation::notations_fixing(&[], stage);
+
+            notations.push(PlaceNotation::from_str(&all_places, stage));
+
+            for pn in &notations {
+                let minimal = pn.minimal_string();
+
+                assert_eq!(PlaceNotation::from_str(&minimal, stage), *pn);
+                assert_eq!(
+                    PlaceNotation::from_multiple_string(&minimal, stage),
+                    vec![*pn]
+                );
+            }
+        }
+    }
+
     #[test]
     fn split_many_and_change_accum() {
         fn test(string: &str, stage: Stage, result: Change) {
@@ -8,6 +8,9 @@ use std::cmp::Ordering;
 
 pub type ProofGroups = Vec<Vec<usize>>;
 
+// The largest stage for which `prove_all` will allocate a table of every row on that stage
+const MAX_PROVE_ALL_TABLE_STAGE: usize = 9;
+
 pub fn fill_from_iterator<T: Sized>(iter: &mut impl Iterator<Item = T>, slice: &mut [T]) -> bool {
     for element in slice.iter_mut() {
         if let Some(v) = iter.next() {
@@ -516,6 +519,29 @@ impl ProvingContext for CompactHashProver {
     }
 }
 
+// Proves every touch in `touches`, reusing the same `CompactHashProver` (and so the same table of
+// rows) for as long as consecutive touches have the same stage.  Touches on stages too large for
+// a table of the whole extent are proved with a `NaiveProver`.
+pub fn prove_all(touches: &[Touch]) -> Vec<bool> {
+    let mut prover: Option<CompactHashProver> = None;
+
+    touches
+        .iter()
+        .map(|touch| {
+            if touch.stage.as_usize() > MAX_PROVE_ALL_TABLE_STAGE {
+                return NaiveProver {}.prove_touch(touch);
+            }
+
+            match &mut prover {
+                Some(p) if p.stage == touch.stage => p.prove_touch(touch),
+                _ => prover
+                    .insert(CompactHashProver::from_stage(touch.stage))
+                    .prove_touch(touch),
+            }
+        })
+        .collect()
+}
+
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct CompactHashIterator<'a, I: Iterator<Item = Bell>, T: FnMut(&[Bell], &mut Change)> {
     hash_prover: &'a mut CompactHashProver,
@@ -732,7 +758,7 @@ pub fn canon_full_cyclic_palendromic(slice: &[Bell], change: &mut Change) {
 #[cfg(test)]
 mod tests {
     use crate::proving::*;
-    use crate::{DefaultScoring, Touch};
+    use crate::{one_part_spliced_touch, DefaultScoring, Method, Touch};
 
     fn full_proof_test_touches() -> Vec<(Touch, Vec<Vec<usize>>)> {
         vec![
@@ -790,6 +816,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bulk_proving() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let plain_bob_royal =
+            Method::from_str("Plain Bob Royal", "x10x10x10x10x10,12", Stage::ROYAL);
+
+        let mut touches: Vec<Touch> = test_touches().into_iter().map(|(t, _)| t).collect();
+
+        touches.push(Touch::single_course(
+            &plain_bob,
+            &Change::rounds(Stage::MAJOR),
+        ));
+        touches.push(one_part_spliced_touch(
+            &[("P", &plain_bob)],
+            &[],
+            "PPPPPPPPP",
+        ));
+        touches.push(Touch::single_course(
+            &plain_bob_royal,
+            &Change::rounds(Stage::ROYAL),
+        ));
+        touches.push(one_part_spliced_touch(
+            &[("P", &plain_bob_royal)],
+            &[],
+            "PPPPPPPPPP",
+        ));
+        touches.push(Touch::from("123456\n214365\n123456\n123456"));
+
+        let results = prove_all(&touches);
+
+        assert_eq!(
+            results,
+            vec![true, true, false, true, false, true, false, false]
+        );
+
+        for (touch, result) in touches.iter().zip(results.iter()) {
+            assert_eq!(touch.is_true(), *result);
+        }
+
+        assert!(prove_all(&[]).is_empty());
+    }
+
     #[test]
     fn canon_func_fixed_treble_cyclic() {
         for (orig, canon) in &[
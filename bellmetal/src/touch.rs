@@ -1,17 +1,33 @@
 use crate::{
-    Bell, Change, ChangeAccumulator, FullProvingContext, Method, MusicScoring, NaiveProver,
-    PlaceNotation, ProvingContext, Stage, Stroke, TouchIterator, Transposition,
+    calling_position_name, Bell, Call, Change, ChangeAccumulator, CoursingOrder,
+    CoursingOrderIterator, FullProvingContext, InvalidRowError, Mask, MaskMethods, Method,
+    MultiplicationIterator, MusicScoring, NaiveProver, Number, Parity, Place, PlaceNotation,
+    ProvingContext, Stage, Stroke, TouchIterator, Transposition,
 };
 
+use crate::music_scoring::{count_wraps_of, MusicBreakdown, MusicScheme, NamedRow};
 use crate::proving::ProofGroups;
 use crate::utils::AndNext;
 use itertools::Itertools;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::error;
 use std::fmt;
 use std::iter::Cloned;
 use std::marker::PhantomData;
 
+// The bells either side of `bell` in the coursing order of a lead head, or `None` if `bell` isn't
+// in the coursing order (i.e. it is the treble)
+fn coursing_neighbours(lead_head: &impl Transposition, bell: Bell) -> Option<(Bell, Bell)> {
+    let coursing_order = CoursingOrder::from_leadhead(lead_head);
+    let mut iter = coursing_order.iter();
+    let order: Vec<Bell> = (0..iter.length()).map(|_| iter.next()).collect();
+    let i = order.iter().position(|b| *b == bell)?;
+    let len = order.len();
+
+    Some((order[(i + len - 1) % len], order[(i + 1) % len]))
+}
+
 fn depth_first_search(
     edges: &[(usize, usize)],
     groups: &mut Vec<Option<usize>>,
@@ -87,6 +103,15 @@ fn falseness_to_table(falseness_map: &[Vec<usize>]) -> HashMap<usize, usize> {
     hash_map
 }
 
+// The label used to annotate the row where a call is made
+fn call_annotation(call_char: char) -> String {
+    match call_char {
+        '-' | 'b' | 'B' => String::from("Bob"),
+        's' | 'S' => String::from("Single"),
+        c => c.to_string(),
+    }
+}
+
 #[derive(Copy, Clone, Hash, Debug)]
 pub struct Row<'a> {
     pub index: usize,
@@ -258,6 +283,117 @@ impl<'a> PartialOrd for Row<'a> {
     }
 }
 
+// The place notation of the change from one row to the next, i.e. the places in which both rows
+// have the same bell
+fn place_notation_between(from: &[Bell], to: &[Bell], stage: Stage) -> PlaceNotation {
+    let mut places = Mask::empty();
+
+    for (i, (a, b)) in from.iter().zip(to.iter()).enumerate() {
+        if a == b {
+            places.add(i as Number);
+        }
+    }
+
+    PlaceNotation { places, stage }
+}
+
+// A touch stored as the place notation of its plain lead and one character per lead giving the
+// call made in that lead (or `p` if the lead is plain)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct CompactTouch {
+    pub method_pn: String,
+    pub calling: String,
+    pub stage: Stage,
+}
+
+// The error returned when a block of place notation doesn't come round, holding the row that it
+// finishes on instead
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct NotRoundBlock {
+    pub leftover_change: Change,
+}
+
+impl fmt::Display for NotRoundBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Block finishes on '{}' instead of rounds",
+            self.leftover_change
+        )
+    }
+}
+
+impl error::Error for NotRoundBlock {}
+
+// The error returned by `Touch::to_compact` when a lead (as separated by ruleoffs) isn't a lead of
+// the method that the touch is being compressed as, holding the index of the lead's first row
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct NotMethodLead {
+    pub lead_start: usize,
+}
+
+impl fmt::Display for NotMethodLead {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The lead starting at row {} isn't a lead of the method",
+            self.lead_start
+        )
+    }
+}
+
+impl error::Error for NotMethodLead {}
+
+// The ways in which a call stored in a touch can fail to fit the rows of that touch.  Each
+// variant holds the index of the row where the call was made and the call's notation.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CallError {
+    UnknownCall(usize, char),
+    NotAtLeadEnd(usize, char),
+    WrongPlaceNotation(usize, char),
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallError::UnknownCall(i, c) => write!(f, "Unknown call '{}' at row {}", c, i),
+            CallError::NotAtLeadEnd(i, c) => {
+                write!(f, "Call '{}' at row {} doesn't finish a lead", c, i)
+            }
+            CallError::WrongPlaceNotation(i, c) => write!(
+                f,
+                "The changes after row {} don't match the place notation of call '{}'",
+                i, c
+            ),
+        }
+    }
+}
+
+impl error::Error for CallError {}
+
+// The ways in which a list of row strings can fail to make a touch.  The indices are those of the
+// offending strings.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RowStringsError {
+    Empty,
+    InvalidRow(usize, InvalidRowError),
+    WrongStage(usize),
+}
+
+impl fmt::Display for RowStringsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowStringsError::Empty => write!(f, "Cannot create a touch with no rows"),
+            RowStringsError::InvalidRow(i, e) => write!(f, "Row {} is invalid: {:?}", i, e),
+            RowStringsError::WrongStage(i) => {
+                write!(f, "Row {} has a different stage to the first row", i)
+            }
+        }
+    }
+}
+
+impl error::Error for RowStringsError {}
+
 #[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct Touch {
     pub stage: Stage,
@@ -267,6 +403,7 @@ pub struct Touch {
     ruleoffs: Vec<usize>,
     method_names: HashMap<usize, String>,
     calls: HashMap<usize, char>,
+    annotations: Vec<(usize, String)>,
     pub leftover_change: Change,
 }
 
@@ -311,8 +448,37 @@ impl Touch {
         }
     }
 
+    // Calls `call_char` at the row at `index`.  If that row already has a call, the new call
+    // replaces both it and its annotation
     pub fn add_call(&mut self, index: usize, call_char: char) {
-        self.calls.insert(index, call_char);
+        let label = call_annotation(call_char);
+
+        if let Some(old_call_char) = self.calls.insert(index, call_char) {
+            let old_label = call_annotation(old_call_char);
+
+            if let Some(annotation) = self
+                .annotations
+                .iter_mut()
+                .find(|(i, l)| *i == index && *l == old_label)
+            {
+                annotation.1 = label;
+                return;
+            }
+        }
+
+        self.add_annotation(index, &label);
+    }
+
+    // Attaches a label to the row at `index`, after any labels that row already has
+    pub fn add_annotation(&mut self, index: usize, label: &str) {
+        let pos = self.annotations.partition_point(|(i, _)| *i <= index);
+
+        self.annotations.insert(pos, (index, String::from(label)));
+    }
+
+    // The labels attached to rows of the touch, sorted by row index
+    pub fn annotations(&self) -> &[(usize, String)] {
+        &self.annotations
     }
 
     pub fn add_ruleoff(&mut self, index: usize) {
@@ -334,8 +500,9 @@ impl Touch {
         self.bells.extend(iterator.bell_iter());
         self.ruleoffs
             .extend(iterator.ruleoff_iter().map(|x| x + len));
-        self.calls
-            .extend(iterator.call_iter().map(|(ind, call)| (ind + len, call)));
+        for (ind, call) in iterator.call_iter() {
+            self.add_call(ind + len, call);
+        }
         self.method_names.extend(
             iterator
                 .method_name_iter()
@@ -348,6 +515,35 @@ impl Touch {
         self.length += iterator.length();
     }
 
+    // Appends the rows of another touch to the end of this one, along with its ruleoffs, calls,
+    // method names and annotations (re-indexed to their new rows).  Unlike `append_iterator`,
+    // this keeps every annotation rather than just those generated by calls.  Like
+    // `append_iterator`, the rows are copied as they are, so `other` should start from the
+    // leftover change of this touch.
+    pub fn append(&mut self, other: &Touch) {
+        assert_eq!(self.stage, other.stage);
+
+        let len = self.length;
+
+        self.bells.extend_from_slice(&other.bells);
+        self.ruleoffs.extend(other.ruleoffs.iter().map(|x| x + len));
+        self.calls
+            .extend(other.calls.iter().map(|(i, c)| (i + len, *c)));
+        self.method_names.extend(
+            other
+                .method_names
+                .iter()
+                .map(|(i, name)| (i + len, name.clone())),
+        );
+
+        for (i, label) in &other.annotations {
+            self.add_annotation(i + len, label);
+        }
+
+        self.leftover_change.overwrite_from(&other.leftover_change);
+        self.length += other.length;
+    }
+
     pub fn extend_with_place_notation<'a>(
         &mut self,
         pns: impl IntoIterator<Item = &'a PlaceNotation>,
@@ -366,6 +562,327 @@ impl Touch {
         self.length = self.bells.len() / self.stage.as_usize();
     }
 
+    // Extends the touch by a lead of place notation, ruling off after its last change
+    pub fn push_lead(&mut self, lead_pn: &[PlaceNotation]) {
+        if lead_pn.is_empty() {
+            return;
+        }
+
+        self.extend_with_place_notation(lead_pn);
+        self.add_ruleoff(self.length - 1);
+    }
+
+    // Calls a call at the end of the last lead of the touch, replacing the changes at the end of
+    // that lead with the call's place notation
+    pub fn push_call(&mut self, call: &Call) {
+        assert_eq!(self.stage, call.stage);
+
+        let stage = self.stage.as_usize();
+        let call_length = call.place_notations.len();
+
+        if call_length > self.length {
+            panic!("Can't call a call that is longer than the touch");
+        }
+
+        // Remove the changes that the call replaces
+        let new_length = self.length - call_length;
+
+        self.leftover_change
+            .overwrite_from_slice(&self.bells[new_length * stage..(new_length + 1) * stage]);
+        self.bells.truncate(new_length * stage);
+        self.length = new_length;
+
+        self.ruleoffs.retain(|&i| i < new_length);
+        self.calls.retain(|&i, _| i < new_length);
+        self.method_names.retain(|&i, _| i < new_length);
+        self.annotations.retain(|(i, _)| *i < new_length);
+
+        // Add the call
+        self.add_call(self.length, call.notation);
+        self.push_lead(&call.place_notations);
+    }
+
+    // Checks that every call in the touch replaces the changes at the end of a lead (as separated
+    // by ruleoffs) with its place notation, looking up the calls by their notation in `calls`.
+    // Calls are checked in row order, and the first bad call is returned.
+    pub fn validate_calls(&self, calls: &[Call]) -> Result<(), CallError> {
+        let mut touch_calls: Vec<(usize, char)> =
+            self.calls.iter().map(|(i, c)| (*i, *c)).collect();
+
+        touch_calls.sort_unstable();
+
+        for (i, c) in touch_calls {
+            let call = match calls.iter().find(|call| call.notation == c) {
+                Some(call) => call,
+                None => return Err(CallError::UnknownCall(i, c)),
+            };
+
+            let end = i + call.place_notations.len();
+
+            if end > self.length
+                || (end != self.length && self.ruleoffs.binary_search(&(end - 1)).is_err())
+            {
+                return Err(CallError::NotAtLeadEnd(i, c));
+            }
+
+            for (j, pn) in call.place_notations.iter().enumerate() {
+                let actual_pn = place_notation_between(
+                    self.row_or_leftover_slice(i + j),
+                    self.row_or_leftover_slice(i + j + 1),
+                    self.stage,
+                );
+
+                if actual_pn != *pn {
+                    return Err(CallError::WrongPlaceNotation(i, c));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns the indices of the leads (as separated by ruleoffs) whose lead heads have `tenor`
+    // in its home position within the coursing order, i.e. coursing after and being coursed by
+    // the same bells as in the plain course (assuming Plain Bob lead heads)
+    pub fn tenor_course_leads(&self, tenor: Bell) -> Vec<usize> {
+        let home = coursing_neighbours(&Change::rounds(self.stage), tenor);
+
+        self.lead_starts()
+            .iter()
+            .enumerate()
+            .filter(|(_, &start)| {
+                home.is_some() && coursing_neighbours(&self.row_at(start), tenor) == home
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    // The largest number of parts, `n`, such that the touch is made of `n` copies of the same
+    // block of rows, each one transposed by a part end from the last (with the leftover change
+    // continuing the pattern).  Returns `None` if there is no such `n` greater than 1.
+    pub fn part_count(&self) -> Option<usize> {
+        let stage = self.stage.as_usize();
+
+        (2..=self.length)
+            .rev()
+            .filter(|n| self.length % n == 0)
+            .find(|n| {
+                let part_length = self.length / n;
+                let first = self.slice_at(0);
+                let second = self.row_or_leftover_slice(part_length);
+
+                // The part end, stored as a map from bells to bells
+                let mut part_end = vec![Bell::from(0); stage];
+
+                for (a, b) in first.iter().zip(second.iter()) {
+                    part_end[a.as_usize()] = *b;
+                }
+
+                (part_length..=self.length).all(|i| {
+                    self.row_or_leftover_slice(i)
+                        .iter()
+                        .zip(self.slice_at(i - part_length).iter())
+                        .all(|(a, b)| *a == part_end[b.as_usize()])
+                })
+            })
+    }
+
+    // True if every lead head (as separated by ruleoffs) and the leftover change are even rows
+    pub fn is_in_course(&self) -> bool {
+        self.lead_starts()
+            .iter()
+            .all(|&start| self.row_at(start).parity() == Parity::Even)
+            && self.leftover_change.parity() == Parity::Even
+    }
+
+    // The indices of the first row of every lead, as separated by ruleoffs
+    fn lead_starts(&self) -> Vec<usize> {
+        let mut lead_starts = Vec::with_capacity(self.ruleoffs.len() + 1);
+
+        if self.length > 0 {
+            lead_starts.push(0);
+        }
+
+        lead_starts.extend(
+            self.ruleoffs
+                .iter()
+                .map(|x| x + 1)
+                .filter(|x| *x < self.length),
+        );
+
+        lead_starts
+    }
+
+    // The calling position of each call in the touch, in row order, given by where the method's
+    // observation bell is in the lead head following each call (using the same positions as
+    // `Call::at_calling_position`).  Leads are assumed to be the length of `method`'s lead, and a
+    // call that leaves that bell somewhere without a named position is given the name of that
+    // place instead.  Places shared by two positions are named as by `calling_position_name`, so
+    // on Minor a call that leaves the observation bell in 4ths is always `B`, never `M`.
+    pub fn calling_positions(&self, method: &Method) -> Vec<char> {
+        let lead_length = method.lead_length();
+        let observation_bell = method.observation_bell();
+
+        if lead_length == 0 {
+            return Vec::new();
+        }
+
+        let mut call_indices: Vec<usize> = self.calls.keys().cloned().collect();
+
+        call_indices.sort_unstable();
+
+        call_indices
+            .iter()
+            .map(|i| {
+                let lead_end = ((i / lead_length + 1) * lead_length).min(self.length);
+                let place = Place::from(
+                    self.row_or_leftover_slice(lead_end)
+                        .iter()
+                        .position(|b| *b == observation_bell)
+                        .unwrap(),
+                );
+
+                calling_position_name(place, self.stage).unwrap_or_else(|| place.as_char())
+            })
+            .collect()
+    }
+
+    // The number of complete leads of `lead_length` rows in the touch.  The leftover change isn't
+    // part of the touch's rows, so it never completes a lead.
+    pub fn num_leads(&self, lead_length: usize) -> usize {
+        if lead_length == 0 {
+            panic!("Can't count leads of length 0");
+        }
+
+        self.length / lead_length
+    }
+
+    // Splits the touch into sub-touches of `lead_length` rows (the last of which may be shorter),
+    // each transposed so that it starts from rounds.  Ruleoffs, calls and method names are kept
+    // with the rows they belong to.
+    pub fn leads(&self, lead_length: usize) -> impl Iterator<Item = Touch> + '_ {
+        (0..self.length).step_by(lead_length).map(move |start| {
+            let end = (start + lead_length).min(self.length);
+
+            self.segment(start, end, &self.row_at(start).inverse())
+        })
+    }
+
+    // Splits the touch into the rows before `row` and the rows from `row` onwards, where the
+    // second half is transposed so that it starts from rounds
+    pub fn split_at(&self, row: usize) -> (Touch, Touch) {
+        if row > self.length {
+            panic!(
+                "Can't split a touch of length {} at row {}",
+                self.length, row
+            );
+        }
+
+        let split_row = if row == self.length {
+            self.leftover_change.inverse()
+        } else {
+            self.row_at(row).inverse()
+        };
+
+        (
+            self.segment(0, row, &Change::rounds(self.stage)),
+            self.segment(row, self.length, &split_row),
+        )
+    }
+
+    // The rows `start..end` of the touch as a touch of their own, with every row pre-multiplied
+    // by `transposition`.  Ruleoffs, calls and method names are kept with the rows they belong
+    // to.
+    fn segment(&self, start: usize, end: usize, transposition: &Change) -> Touch {
+        let mut segment = Touch::with_capacity(self.stage, end - start, 1, 1, 1);
+
+        for i in start..end {
+            segment
+                .bells
+                .extend(transposition.multiply(&self.row_at(i)).iter());
+        }
+
+        segment.length = end - start;
+        segment.leftover_change = if end < self.length {
+            transposition.multiply(&self.row_at(end))
+        } else {
+            transposition.multiply(&self.leftover_change)
+        };
+
+        let in_segment = |i: usize| i >= start && i < end;
+
+        segment.ruleoffs = self
+            .ruleoffs
+            .iter()
+            .filter(|i| in_segment(**i))
+            .map(|i| i - start)
+            .collect();
+
+        for (i, c) in self.calls.iter().filter(|(i, _)| in_segment(**i)) {
+            segment.add_call(i - start, *c);
+        }
+
+        for (i, name) in self.method_names.iter().filter(|(i, _)| in_segment(**i)) {
+            segment.add_method_name(i - start, name);
+        }
+
+        segment
+    }
+
+    // The indices of the rows where the method changes, given the lengths of the segments (e.g.
+    // leads) that the touch is made of.  Each segment is rung to the last method name given at or
+    // before its first row.
+    pub fn method_changes(&self, segment_lengths: &[usize]) -> Vec<usize> {
+        let mut method_changes = Vec::new();
+        let mut last_method: Option<&str> = None;
+        let mut start = 0;
+
+        for length in segment_lengths {
+            if start >= self.length {
+                break;
+            }
+
+            let method = self
+                .method_names
+                .iter()
+                .filter(|(i, _)| **i <= start)
+                .max_by_key(|(i, _)| **i)
+                .map(|(_, name)| &name[..]);
+
+            if start > 0 && method != last_method {
+                method_changes.push(start);
+            }
+
+            last_method = method;
+            start += length;
+        }
+
+        method_changes
+    }
+
+    // The places of both bells of a handbell pair in every row of the touch
+    pub fn handbell_pair_path(&self, pair: (Bell, Bell)) -> Vec<(Place, Place)> {
+        self.row_iterator()
+            .map(|r| (r.place_of(pair.0), r.place_of(pair.1)))
+            .collect()
+    }
+
+    // An SVG path (as used in the `d` attribute of a `<path>`) tracing `bell`'s line through the
+    // touch, with one point in the centre of the bell's cell in every row
+    pub fn bell_svg_path(&self, bell: Bell, cell_width: f32, cell_height: f32) -> String {
+        self.row_iterator()
+            .enumerate()
+            .map(|(i, r)| {
+                format!(
+                    "{} {} {}",
+                    if i == 0 { 'M' } else { 'L' },
+                    (r.place_of(bell).as_usize() as f32 + 0.5) * cell_width,
+                    (i as f32 + 0.5) * cell_height
+                )
+            })
+            .join(" ")
+    }
+
     pub fn row_at(&self, index: usize) -> Row {
         Row {
             index,
@@ -389,6 +906,15 @@ impl Touch {
         &self.bells[index * stage..(index + 1) * stage]
     }
 
+    // Like `slice_at`, but returns the leftover change for the index one past the last row
+    fn row_or_leftover_slice(&self, index: usize) -> &[Bell] {
+        if index == self.length {
+            self.leftover_change.slice()
+        } else {
+            self.slice_at(index)
+        }
+    }
+
     pub fn bell_at(&self, index: usize) -> Bell {
         self.bells[index]
     }
@@ -397,6 +923,37 @@ impl Touch {
         T::score_touch(self)
     }
 
+    pub fn music_breakdown(&self, scheme: &MusicScheme) -> MusicBreakdown {
+        scheme.breakdown(self)
+    }
+
+    // The number of each type of music that `scheme` scores (in the order of the fields of
+    // `MusicBreakdown`), with a 0 for every type that `scheme` doesn't score.  Touches with the same
+    // signature have the same music as far as `scheme` is concerned.
+    pub fn music_signature(&self, scheme: &MusicScheme) -> Vec<u32> {
+        let breakdown = self.music_breakdown(scheme);
+
+        [
+            (breakdown.four_bell_runs, scheme.four_bell_run),
+            (breakdown.five_bell_runs, scheme.five_bell_run),
+            (breakdown.named_rows, scheme.named_row),
+            (breakdown.crus, scheme.cru),
+            (breakdown.wraps, scheme.wrap),
+        ]
+        .iter()
+        .map(|(count, weight)| if *weight == 0 { 0 } else { *count as u32 })
+        .collect()
+    }
+
+    // The number of times that backrounds is found split over the boundary between two
+    // consecutive rows of the touch
+    pub fn count_backrounds_wraps(&self) -> usize {
+        match NamedRow::Backrounds.change(self.stage) {
+            Some(backrounds) => count_wraps_of(self, &backrounds),
+            None => 0,
+        }
+    }
+
     pub fn number_of_4_bell_runs(&self) -> (usize, usize) {
         let mut run_count_front = 0;
         let mut run_count_back = 0;
@@ -417,10 +974,66 @@ impl Touch {
         NaiveProver {}.prove_touch_canonical(&self, canon)
     }
 
+    // Returns `true` if `row` is one of the rows of the touch.  Like proving, this doesn't include
+    // the leftover change.
+    pub fn contains(&self, row: &Change) -> bool {
+        self.row_iterator().any(|r| r.slice() == row.slice())
+    }
+
+    // The number of different rows in the touch, which is `length` exactly when the touch is true.
+    // Like proving, this doesn't include the leftover change.
+    pub fn distinct_row_count(&self) -> usize {
+        (0..self.length)
+            .map(|i| self.slice_at(i))
+            .collect::<HashSet<&[Bell]>>()
+            .len()
+    }
+
     pub fn is_true(&self) -> bool {
         NaiveProver {}.prove_touch(&self)
     }
 
+    // Checks that the touch is an extent that can actually be rung: it has to contain every row
+    // exactly once, return to its first row, contain as many odd rows as even rows, and never
+    // repeat a row or move a bell by more than one place between consecutive rows
+    pub fn is_extent_valid(&self) -> bool {
+        // Extents on large stages (from 21 bells with a 64-bit `usize`) have too many rows to count,
+        // so no touch can be one
+        let extent_length =
+            match (1..=self.stage.as_usize()).try_fold(1usize, |a, b| a.checked_mul(b)) {
+                Some(l) => l,
+                None => return false,
+            };
+
+        if self.length != extent_length
+            || self.leftover_change.slice() != self.slice_at(0)
+            || !self.is_true()
+        {
+            return false;
+        }
+
+        let num_even_rows = self
+            .row_iterator()
+            .filter(|r| r.parity() == Parity::Even)
+            .count();
+
+        if self.length > 1 && num_even_rows * 2 != self.length {
+            return false;
+        }
+
+        (0..self.length).all(|i| {
+            let row = self.row_or_leftover_slice(i);
+            let next_row = self.row_or_leftover_slice(i + 1);
+
+            (self.length == 1 || row != next_row)
+                && next_row.iter().enumerate().all(|(p, b)| {
+                    let old_place = row.iter().position(|x| x == b).unwrap();
+
+                    old_place + 1 >= p && old_place <= p + 1
+                })
+        })
+    }
+
     pub fn full_truth(&self) -> ProofGroups {
         NaiveProver {}.full_prove_touch(&self)
     }
@@ -708,6 +1321,67 @@ impl Touch {
         )
     }
 
+    // Compresses this touch of `method` into the method's place notation and the call made in
+    // each of its leads (as separated by ruleoffs).  Returns an error if a lead is the wrong length
+    // for `method`, or if a plain lead doesn't have the method's place notation.
+    pub fn to_compact(&self, method: &Method) -> Result<CompactTouch, NotMethodLead> {
+        assert_eq!(self.stage, method.stage);
+
+        let mut lead_ends: Vec<usize> = self
+            .ruleoffs
+            .iter()
+            .map(|x| x + 1)
+            .filter(|x| *x <= self.length)
+            .collect();
+
+        if self.length > 0 && lead_ends.last() != Some(&self.length) {
+            lead_ends.push(self.length);
+        }
+
+        let mut calling = String::with_capacity(lead_ends.len());
+        let mut start = 0;
+
+        for end in lead_ends {
+            if end - start != method.lead_length() {
+                return Err(NotMethodLead { lead_start: start });
+            }
+
+            let call = self
+                .calls
+                .iter()
+                .filter(|(i, _)| **i >= start && **i < end)
+                .min_by_key(|(i, _)| **i)
+                .map(|(_, c)| *c);
+
+            match call {
+                Some(c) => calling.push(c),
+                None => {
+                    let is_plain_lead = (start..end).zip(&method.place_notations).all(|(i, pn)| {
+                        place_notation_between(
+                            self.row_or_leftover_slice(i),
+                            self.row_or_leftover_slice(i + 1),
+                            self.stage,
+                        ) == *pn
+                    });
+
+                    if !is_plain_lead {
+                        return Err(NotMethodLead { lead_start: start });
+                    }
+
+                    calling.push('p');
+                }
+            }
+
+            start = end;
+        }
+
+        Ok(CompactTouch {
+            method_pn: PlaceNotation::notations_to_string_short(&method.place_notations),
+            calling,
+            stage: self.stage,
+        })
+    }
+
     // Functions defined to increase performance by avoiding memory allocations
     pub fn overwrite_from_place_notations(&mut self, place_notations: &[PlaceNotation]) {
         let length = place_notations.len();
@@ -820,46 +1494,150 @@ impl Touch {
             bells: new_bells,
             ruleoffs: self.ruleoffs.clone(),
             calls: self.calls.clone(),
+            annotations: self.annotations.clone(),
             method_names: self.method_names.clone(),
             leftover_change: self.leftover_change.inverted(),
         }
     }
-}
 
-impl Touch {
-    pub fn empty(stage: Stage) -> Touch {
-        Touch {
-            stage,
-            length: 0usize,
+    // Two touches are equivalent if the rows of one can be found by rotating the rows of the
+    // other and/or transposing every row by the same change
+    pub fn is_equivalent(&self, other: &Touch) -> bool {
+        if self.stage != other.stage || self.length != other.length {
+            return false;
+        }
 
-            bells: Vec::with_capacity(0),
-            ruleoffs: Vec::with_capacity(0),
-            calls: HashMap::with_capacity(0),
-            method_names: HashMap::with_capacity(0),
-            leftover_change: Change::empty(),
+        if self.length == 0 {
+            return true;
         }
-    }
 
-    pub fn from_changes(changes: &[Change], leftover_change: Change) -> Touch {
-        let mut bells: Vec<Bell> =
-            Vec::with_capacity(changes.len() * leftover_change.stage().as_usize());
+        let first_row_inverse = self.row_at(0).inverse();
 
-        for c in changes {
-            bells.extend(c.iter());
-        }
+        for rotation in 0..other.length {
+            let transposition =
+                Change::new(other.slice_at(rotation).to_vec()).multiply(&first_row_inverse);
 
-        Touch {
-            stage: leftover_change.stage(),
-            length: changes.len(),
+            let is_match = (0..self.length).all(|i| {
+                let other_row = other.slice_at((i + rotation) % other.length);
 
-            bells,
+                MultiplicationIterator::new(transposition.slice(), self.slice_at(i).iter().cloned())
+                    .eq(other_row.iter().cloned())
+            });
+
+            if is_match {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // The touch starts with rounds at `stroke` if the last of its leading rows of rounds (i.e. the
+    // row from which the first change is rung) is at that stroke
+    pub fn starts_with_rounds_at(&self, stroke: Stroke) -> bool {
+        let leading_rounds = (0..self.length)
+            .take_while(|i| self.row_at(*i).is_rounds())
+            .count();
+
+        leading_rounds > 0 && Stroke::from_index(leading_rounds - 1) == stroke
+    }
+
+    pub fn ends_with_rounds_at(&self, stroke: Stroke) -> bool {
+        self.leftover_change.is_rounds() && Stroke::from_index(self.length) == stroke
+    }
+
+    // The rows of the touch (not including the leftover change) in the order they are rung, with a
+    // `None` before every handstroke row to mark the handstroke gap
+    pub fn with_handstroke_gap(&self) -> Vec<Option<Change>> {
+        let mut rows = Vec::with_capacity(self.length + self.length / 2 + 1);
+
+        for i in 0..self.length {
+            if Stroke::from_index(i) == Stroke::Hand {
+                rows.push(None);
+            }
+
+            rows.push(Some(Change::from_iterator(
+                self.slice_at(i).iter().cloned(),
+            )));
+        }
+
+        rows
+    }
+
+    // The rows of the touch as strings, followed by the leftover change.  This is the inverse of
+    // `Touch::from_row_strings`.
+    pub fn to_row_strings(&self) -> Vec<String> {
+        (0..self.length)
+            .map(|i| self.slice_at(i).iter().map(|b| b.as_char()).collect())
+            .chain(std::iter::once(self.leftover_change.ugly_string()))
+            .collect()
+    }
+
+    // Competition test pieces must be started from rounds and brought round at given strokes
+    pub fn is_valid_test_piece(&self, start: Stroke, end: Stroke) -> bool {
+        self.starts_with_rounds_at(start) && self.ends_with_rounds_at(end)
+    }
+}
+
+impl Touch {
+    pub fn empty(stage: Stage) -> Touch {
+        Touch {
+            stage,
+            length: 0usize,
+
+            bells: Vec::with_capacity(0),
+            ruleoffs: Vec::with_capacity(0),
+            calls: HashMap::with_capacity(0),
+            annotations: Vec::with_capacity(0),
+            method_names: HashMap::with_capacity(0),
+            leftover_change: Change::empty(),
+        }
+    }
+
+    pub fn from_changes(changes: &[Change], leftover_change: Change) -> Touch {
+        let mut bells: Vec<Bell> =
+            Vec::with_capacity(changes.len() * leftover_change.stage().as_usize());
+
+        for c in changes {
+            bells.extend(c.iter());
+        }
+
+        Touch {
+            stage: leftover_change.stage(),
+            length: changes.len(),
+
+            bells,
             ruleoffs: vec![changes.len() - 1],
             calls: HashMap::with_capacity(0),
+            annotations: Vec::with_capacity(0),
             method_names: HashMap::with_capacity(0),
             leftover_change,
         }
     }
 
+    // Builds a touch from a list of row strings, the last of which is the leftover change.  Unlike
+    // `Touch::from`, this returns an error rather than panicking if any of the strings aren't
+    // rows of the same stage.
+    pub fn from_row_strings(rows: &[&str]) -> Result<Touch, RowStringsError> {
+        let stage = match rows.first() {
+            Some(r) => r.len(),
+            None => return Err(RowStringsError::Empty),
+        };
+
+        for (i, r) in rows.iter().enumerate() {
+            if let Err(e) = Change::try_from_str(r) {
+                return Err(RowStringsError::InvalidRow(i, e));
+            }
+
+            if r.len() != stage {
+                return Err(RowStringsError::WrongStage(i));
+            }
+        }
+
+        // `Touch::from` only reads uppercase bell names
+        Ok(Touch::from(&rows.join("\n").to_ascii_uppercase()[..]))
+    }
+
     pub fn with_capacity(
         stage: Stage,
         change_capacity: usize,
@@ -874,6 +1652,7 @@ impl Touch {
             bells: Vec::with_capacity(change_capacity * stage.as_usize()),
             ruleoffs: Vec::with_capacity(ruleoff_capacity),
             calls: HashMap::with_capacity(call_capacity),
+            annotations: Vec::with_capacity(call_capacity),
             method_names: HashMap::with_capacity(method_name_capacity),
             leftover_change: Change::rounds(stage),
         }
@@ -898,6 +1677,97 @@ impl Touch {
         touch
     }
 
+    // Repeats a division of place notation (such as a six of Stedman) from rounds until it comes
+    // back to rounds, ruling off after each division.  This is the plain course of a principle,
+    // which has no hunt bell to define its leads.
+    pub fn principle_course(division_pn: &[PlaceNotation], stage: Stage) -> Touch {
+        for pn in division_pn {
+            assert_eq!(pn.stage, stage);
+        }
+
+        if division_pn.is_empty() {
+            return Touch::with_capacity(stage, 0, 0, 0, 0);
+        }
+
+        let num_divisions = PlaceNotation::lead_order(division_pn);
+        let mut touch = Touch::with_capacity(
+            stage,
+            num_divisions * division_pn.len(),
+            num_divisions,
+            0,
+            0,
+        );
+
+        for _ in 0..num_divisions {
+            touch.push_lead(division_pn);
+        }
+
+        touch
+    }
+
+    // Rebuilds a touch stored by `to_compact`, looking up the calls by their notation in `calls`
+    pub fn from_compact(compact: &CompactTouch, calls: &[Call]) -> Touch {
+        let mut touch = Touch::with_capacity(compact.stage, 0, 0, 0, 0);
+
+        if compact.calling.is_empty() {
+            return touch;
+        }
+
+        let lead_pn = PlaceNotation::from_multiple_string(&compact.method_pn, compact.stage);
+
+        for c in compact.calling.chars() {
+            touch.push_lead(&lead_pn);
+
+            if c != 'p' {
+                match calls.iter().find(|call| call.notation == c) {
+                    Some(call) => touch.push_call(call),
+                    None => panic!("No call has notation '{}'", c),
+                }
+            }
+        }
+
+        touch
+    }
+
+    // Builds a touch of `method` from a call string with one character per lead, which is either
+    // the notation of the call made at the end of that lead or `p` (or a space) for a plain lead.
+    // Returns an error naming the lead end row of the first lead whose character isn't in
+    // `calls`.
+    pub fn from_call_string(
+        method: &Method,
+        calls: &[Call],
+        call_string: &str,
+    ) -> Result<Touch, CallError> {
+        let mut touch = Touch::with_capacity(method.stage, 0, 0, 0, 0);
+
+        for c in call_string.chars() {
+            touch.push_lead(&method.place_notations);
+
+            if c != 'p' && c != ' ' {
+                match calls.iter().find(|call| call.notation == c) {
+                    Some(call) => touch.push_call(call),
+                    None => return Err(CallError::UnknownCall(touch.length - 1, c)),
+                }
+            }
+        }
+
+        Ok(touch)
+    }
+
+    // Builds the touch generated by a block of place notation, returning an error if it doesn't
+    // come round
+    pub fn try_round_block(pns: &[PlaceNotation]) -> Result<Touch, NotRoundBlock> {
+        let touch = Touch::from(pns);
+
+        if touch.leftover_change.is_rounds() {
+            Ok(touch)
+        } else {
+            Err(NotRoundBlock {
+                leftover_change: touch.leftover_change,
+            })
+        }
+    }
+
     pub fn from_iterator<'b, I>(iterator: &I) -> Touch
     where
         I: TouchIterator<'b>,
@@ -1093,8 +1963,10 @@ impl<'a> TouchIterator<'a> for BasicTouchIterator<'a> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        canon_full_cyclic, one_part_spliced_touch, Call, DefaultScoring, Method, PlaceNotation,
-        Stage, Touch, Transposition,
+        canon_full_cyclic, extent, extent_touch, one_part_spliced_touch, single_method_touch, Bell,
+        Call, CallError, Change, DefaultScoring, InvalidRowError, Method, MusicScheme,
+        NotMethodLead, NotRoundBlock, Place, PlaceNotation, RowStringsError, Stage, Stroke, Touch,
+        TouchIterator, Transposition,
     };
 
     #[test]
@@ -1306,6 +2178,900 @@ P   \x1b[91;49m1\x1b[97;49m64\x1b[96;49m8\x1b[97;49m2735\x1b[0m
         }
     }
 
+    #[test]
+    fn equivalence() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let st_clements = Method::from_str("St Clement's Bob Minor", "x16x36x16,12", Stage::MINOR);
+
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+        let rotated_course = Touch::single_course(&plain_bob, plain_bob.lead_head());
+        let transposed_course = Touch::single_course(&plain_bob, &Change::from("342156"));
+        let other_method = Touch::single_course(&st_clements, &Change::rounds(Stage::MINOR));
+
+        assert_eq!(plain_course.length, other_method.length);
+
+        assert!(plain_course.is_equivalent(&plain_course));
+        assert!(plain_course.is_equivalent(&rotated_course));
+        assert!(plain_course.is_equivalent(&transposed_course));
+        assert!(rotated_course.is_equivalent(&transposed_course));
+        assert!(!plain_course.is_equivalent(&other_method));
+    }
+
+    #[test]
+    fn incremental_extension() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        // Plain leads
+        let mut touch = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        for _ in 0..7 {
+            touch.push_lead(&plain_bob.place_notations);
+        }
+
+        assert_eq!(
+            touch,
+            Touch::single_course(&plain_bob, &Change::rounds(Stage::MAJOR))
+        );
+
+        // Called leads
+        let mut touch = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        touch.push_lead(&plain_bob.place_notations);
+        touch.push_call(&bob);
+
+        assert_eq!(
+            touch,
+            plain_bob.get_lead_fragment_with_calls(0, plain_bob.lead_length(), &[bob])
+        );
+    }
+
+    #[test]
+    fn annotations() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        let mut touch = Touch::from_call_string(&plain_bob, &[bob.clone()], "--p").unwrap();
+
+        assert_eq!(
+            touch.annotations(),
+            &[(15, String::from("Bob")), (31, String::from("Bob"))][..]
+        );
+
+        // Labels are kept in row order
+        touch.add_annotation(40, "Fire out");
+        touch.add_annotation(0, "Go");
+        touch.add_annotation(15, "Before");
+
+        assert_eq!(
+            touch.annotations(),
+            &[
+                (0, String::from("Go")),
+                (15, String::from("Bob")),
+                (15, String::from("Before")),
+                (31, String::from("Bob")),
+                (40, String::from("Fire out")),
+            ][..]
+        );
+
+        // Calls that have been overwritten lose their annotations
+        let mut touch = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        touch.push_lead(&plain_bob.place_notations);
+        touch.push_call(&bob);
+        touch.push_call(&bob);
+
+        assert_eq!(touch.annotations(), &[(15, String::from("Bob"))][..]);
+
+        // Re-calling a row replaces its call annotation, but keeps any other labels
+        touch.add_annotation(15, "Before");
+        touch.add_call(15, 's');
+
+        assert_eq!(touch.calls.get(&15), Some(&'s'));
+        assert_eq!(
+            touch.annotations(),
+            &[(15, String::from("Single")), (15, String::from("Before"))][..]
+        );
+
+        touch.add_call(15, 's');
+
+        assert_eq!(
+            touch.annotations(),
+            &[(15, String::from("Single")), (15, String::from("Before"))][..]
+        );
+    }
+
+    #[test]
+    fn tenor_course_leads() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MAJOR));
+
+        for bell in &['7', '8'] {
+            assert_eq!(
+                plain_course.tenor_course_leads(Bell::from(*bell)),
+                (0..7).collect::<Vec<usize>>()
+            );
+        }
+
+        // A bob at Wrong leaves the 8th coursing 6-8-7, but the 7th now courses after the 3rd
+        let wrong = Touch::from_call_string(&plain_bob, &[bob.clone()], "-pp").unwrap();
+
+        assert_eq!(wrong.tenor_course_leads(Bell::from('8')), vec![0, 1, 2]);
+        assert_eq!(wrong.tenor_course_leads(Bell::from('7')), vec![0]);
+
+        // A bob at Before (where the tenor makes the bob) takes both tenors out of course
+        let before = Touch::from_call_string(&plain_bob, &[bob], "pp-p").unwrap();
+
+        assert_eq!(before.tenor_course_leads(Bell::from('8')), vec![0, 1, 2]);
+        assert_eq!(before.tenor_course_leads(Bell::from('7')), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn call_strings() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let calls = vec![
+            Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR),
+            Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MINOR),
+        ];
+
+        let touch = Touch::from_call_string(&plain_bob, &calls, "pppp-pppp-pppp-").unwrap();
+
+        assert_eq!(touch.length, 180);
+        assert!(touch.leftover_change.is_rounds());
+        assert_eq!(
+            Touch::from_call_string(&plain_bob, &calls, "    -    -    -"),
+            Ok(touch)
+        );
+
+        assert_eq!(
+            Touch::from_call_string(&plain_bob, &calls, "-p s p"),
+            Touch::from_call_string(&plain_bob, &calls, "-  s  ")
+        );
+        assert_eq!(
+            Touch::from_call_string(&plain_bob, &calls, "ppppp"),
+            Ok(Touch::single_course(
+                &plain_bob,
+                &Change::rounds(Stage::MINOR)
+            ))
+        );
+        assert_eq!(
+            Touch::from_call_string(&plain_bob, &calls, "p-x"),
+            Err(CallError::UnknownCall(35, 'x'))
+        );
+    }
+
+    #[test]
+    fn part_counts() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR);
+
+        let calls = [bob];
+        let build = |calling: &str| Touch::from_call_string(&plain_bob, &calls, calling).unwrap();
+
+        for (calling, parts) in &[
+            ("pppp-pppp-pppp-", Some(3)), // HHH
+            ("ppppp", Some(5)),           // The plain course is a 5-part of single leads
+            ("-pp", None),
+            ("p", None),
+            ("", None),
+        ] {
+            assert_eq!(build(calling).part_count(), *parts);
+        }
+
+        let bobs_only = build("---");
+
+        assert!(bobs_only.leftover_change.is_rounds());
+        assert_eq!(bobs_only.part_count(), Some(3));
+    }
+
+    #[test]
+    fn in_course() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MAJOR);
+
+        assert!(Touch::single_course(&plain_bob, &Change::rounds(Stage::MAJOR)).is_in_course());
+
+        // Bobs keep every lead head in course, but a single takes the rest of the touch out of
+        // course
+        let calls = [bob, single];
+
+        for (calling, in_course) in &[("ppp--", true), ("-psp", false), ("pppppps", false)] {
+            let touch = Touch::from_call_string(&plain_bob, &calls, calling).unwrap();
+
+            assert_eq!(touch.is_in_course(), *in_course);
+        }
+    }
+
+    #[test]
+    fn calling_positions() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        // A single bob at each calling position should be named after that position
+        for position in &['B', 'M', 'W', 'H'] {
+            let lead = bob.at_calling_position(&plain_bob, *position).unwrap();
+            let call_string = "p".repeat(lead) + "-p";
+            let touch = Touch::from_call_string(&plain_bob, &[bob.clone()], &call_string).unwrap();
+
+            assert_eq!(touch.calling_positions(&plain_bob), vec![*position]);
+        }
+
+        // Three courses with a bob at Home come round
+        let h_lead = bob.at_calling_position(&plain_bob, 'H').unwrap();
+
+        let mut course: Vec<Vec<&Call>> = vec![Vec::new(); h_lead + 1];
+
+        course[h_lead].push(&bob);
+
+        let touch_calls: Vec<Vec<&Call>> = (0..3).flat_map(|_| course.iter().cloned()).collect();
+        let touch = single_method_touch(&plain_bob, "P", &touch_calls);
+
+        assert_eq!(touch.leftover_change, Change::rounds(Stage::MAJOR));
+        assert!(touch.is_true());
+        assert_eq!(touch.calling_positions(&plain_bob), vec!['H', 'H', 'H']);
+
+        // A course called `W H`
+        let w_lead = bob.at_calling_position(&plain_bob, 'W').unwrap();
+
+        course[w_lead].push(&bob);
+
+        let touch = single_method_touch(&plain_bob, "P", &course);
+
+        assert_eq!(touch.calling_positions(&plain_bob), vec!['W', 'H']);
+
+        // On Minor, `B` and `M` are both 4ths, so calls there are always named `B`
+        let plain_bob_minor = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let bob_minor = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR);
+        let b_lead = bob_minor
+            .at_calling_position(&plain_bob_minor, 'B')
+            .unwrap();
+
+        assert_eq!(
+            bob_minor.at_calling_position(&plain_bob_minor, 'M'),
+            Some(b_lead)
+        );
+
+        let call_string: String = "p".repeat(b_lead) + "-p";
+        let touch = Touch::from_call_string(&plain_bob_minor, &[bob_minor], &call_string).unwrap();
+
+        assert_eq!(touch.calling_positions(&plain_bob_minor), vec!['B']);
+    }
+
+    #[test]
+    fn num_leads() {
+        let cambridge = Method::from_str(
+            "Cambridge Surprise Major",
+            "x38x14x1258x36x14x58x16x78,12",
+            Stage::MAJOR,
+        );
+
+        let mut touch = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        assert_eq!(touch.num_leads(32), 0);
+
+        for _ in 0..3 {
+            touch.push_lead(&cambridge.place_notations);
+        }
+
+        assert_eq!(touch.length, 96);
+        assert_eq!(touch.num_leads(32), 3);
+
+        // A partial lead doesn't count
+        touch.extend_with_place_notation(&cambridge.place_notations[..5]);
+
+        assert_eq!(touch.num_leads(32), 3);
+        assert_eq!(touch.num_leads(16), 6);
+    }
+
+    #[test]
+    fn leads() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        let mut touch = Touch::from_call_string(&plain_bob, &[bob.clone()], "-p-").unwrap();
+
+        touch.extend_with_place_notation(&plain_bob.place_notations[..4]);
+
+        let leads: Vec<Touch> = touch.leads(16).collect();
+
+        assert_eq!(leads.len(), 4);
+        assert_eq!(leads[3].length, 4);
+
+        for lead in &leads {
+            assert!(lead.row_at(0).is_rounds());
+        }
+
+        // The first lead hasn't moved, and the plain lead is just the plain lead of the method
+        assert_eq!(leads[0], {
+            let mut lead = plain_bob.plain_lead.clone();
+
+            lead.push_call(&bob);
+            lead
+        });
+        assert_eq!(leads[1], plain_bob.plain_lead);
+
+        // Appending the leads back onto each other should give back the original touch
+        let mut joined = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        for lead in &leads {
+            let lead_head = joined.leftover_change.clone();
+
+            joined.append_iterator(&lead.iter().transfigure(&lead_head));
+        }
+
+        assert_eq!(joined, touch);
+    }
+
+    #[test]
+    fn call_validation() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MINOR);
+        let calls = [bob.clone(), single];
+
+        let touch = Touch::from_call_string(&plain_bob, &calls, "-ps").unwrap();
+
+        assert_eq!(touch.validate_calls(&calls), Ok(()));
+        assert_eq!(
+            touch.validate_calls(&[bob.clone()]),
+            Err(CallError::UnknownCall(35, 's'))
+        );
+
+        // A bob in the middle of a lead doesn't replace a lead end
+        let mut mid_lead = touch.clone();
+
+        mid_lead.add_call(4, '-');
+
+        assert_eq!(
+            mid_lead.validate_calls(&calls),
+            Err(CallError::NotAtLeadEnd(4, '-'))
+        );
+
+        // A bob marked on a plain lead end doesn't match the rows
+        let mut plain_lead = touch.clone();
+
+        plain_lead.add_call(23, '-');
+
+        assert_eq!(
+            plain_lead.validate_calls(&calls),
+            Err(CallError::WrongPlaceNotation(23, '-'))
+        );
+
+        assert_eq!(
+            Touch::with_capacity(Stage::MINOR, 0, 0, 0, 0).validate_calls(&calls),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn principle_courses() {
+        for (pn, stage, length) in &[
+            // A quick six and a slow six of Stedman
+            ("3.1.5.3.1.3.1.3.5.1.3.1", Stage::DOUBLES, 60),
+            ("3.1.7.3.1.3.1.3.7.1.3.1", Stage::TRIPLES, 84),
+            ("x16", Stage::MINOR, 12),
+            ("1", Stage::SINGLES, 2),
+        ] {
+            let division_pn = PlaceNotation::from_multiple_string(pn, *stage);
+            let touch = Touch::principle_course(&division_pn, *stage);
+
+            assert_eq!(touch.length, *length);
+            assert_eq!(touch.leftover_change, Change::rounds(*stage));
+            assert!(touch.is_true());
+        }
+
+        assert_eq!(
+            Touch::principle_course(&[], Stage::MINOR),
+            Touch::with_capacity(Stage::MINOR, 0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn append() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        let mut first_part = Touch::from_call_string(&plain_bob, &[bob.clone()], "-").unwrap();
+
+        first_part.add_annotation(0, "Go");
+
+        // Start the second part from where the first part finishes
+        let mut second_part = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        second_part.leftover_change = first_part.leftover_change.clone();
+        second_part.push_lead(&plain_bob.place_notations);
+        second_part.push_lead(&plain_bob.place_notations);
+        second_part.push_call(&bob);
+        second_part.add_annotation(20, "Stand");
+
+        let mut touch = first_part.clone();
+
+        touch.append(&second_part);
+
+        assert_eq!(touch.length, 48);
+        assert_eq!(touch.leftover_change, second_part.leftover_change);
+        assert_eq!(
+            touch.annotations(),
+            &[
+                (0, String::from("Go")),
+                (15, String::from("Bob")),
+                (36, String::from("Stand")),
+                (47, String::from("Bob")),
+            ][..]
+        );
+        assert_eq!(touch.to_compact(&plain_bob).unwrap().calling, "-p-");
+        assert!(touch.is_true());
+
+        // The rows should be the same as ringing the touch in one go
+        let whole_touch = Touch::from_call_string(&plain_bob, &[bob], "-p-").unwrap();
+
+        assert!(whole_touch
+            .row_iterator()
+            .zip(touch.row_iterator())
+            .all(|(a, b)| a == b));
+    }
+
+    #[test]
+    fn compact() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MAJOR);
+        let calls = [bob, single];
+
+        for calling in &["-ps-", "---", "ppppppp", ""] {
+            let touch = Touch::from_call_string(&plain_bob, &calls, calling).unwrap();
+            let compact = touch.to_compact(&plain_bob).unwrap();
+
+            assert_eq!(compact.calling, *calling);
+            assert_eq!(compact.stage, Stage::MAJOR);
+            assert_eq!(
+                PlaceNotation::from_multiple_string(&compact.method_pn, Stage::MAJOR),
+                plain_bob.place_notations
+            );
+
+            let rebuilt = Touch::from_compact(&compact, &calls);
+
+            assert_eq!(rebuilt.length, touch.length);
+            assert_eq!(rebuilt.leftover_change, touch.leftover_change);
+            assert!(rebuilt
+                .row_iterator()
+                .zip(touch.row_iterator())
+                .all(|(a, b)| a == b));
+            assert_eq!(rebuilt.to_compact(&plain_bob), Ok(compact));
+        }
+
+        // Touches of other methods can't be compressed as Plain Bob
+        let little_bob = Method::from_str("Little Bob Major", "x18x14,12", Stage::MAJOR);
+        let little_bob_touch = Touch::single_course(&little_bob, &Change::rounds(Stage::MAJOR));
+
+        assert_eq!(
+            little_bob_touch.to_compact(&plain_bob),
+            Err(NotMethodLead { lead_start: 0 })
+        );
+
+        let double_bob = Method::from_str("Double Bob Major", "x18x14x58x18,12", Stage::MAJOR);
+        let double_bob_touch = Touch::from_call_string(&double_bob, &[], "pp").unwrap();
+
+        assert_eq!(
+            double_bob_touch.to_compact(&plain_bob),
+            Err(NotMethodLead { lead_start: 0 })
+        );
+    }
+
+    #[test]
+    fn round_blocks() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course: Vec<PlaceNotation> = plain_bob
+            .place_notations
+            .iter()
+            .cycle()
+            .take(plain_bob.lead_length() * 5)
+            .cloned()
+            .collect();
+
+        assert_eq!(
+            Touch::try_round_block(&plain_course),
+            Ok(Touch::from(&plain_course[..]))
+        );
+        assert_eq!(
+            Touch::try_round_block(&plain_bob.place_notations),
+            Err(NotRoundBlock {
+                leftover_change: Change::from("135264")
+            })
+        );
+    }
+
+    #[test]
+    fn split_at() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        let touch = Touch::from_call_string(&plain_bob, &[bob], "-p-").unwrap();
+
+        for &row in &[0, 1, 10, 16, 31, 47, 48] {
+            let (first, second) = touch.split_at(row);
+
+            assert_eq!(first.length, row);
+            assert_eq!(second.length, touch.length - row);
+            assert!(row == touch.length || second.row_at(0).is_rounds());
+            assert_eq!(first.leftover_change, {
+                if row == touch.length {
+                    touch.leftover_change.clone()
+                } else {
+                    Change::from_iterator(touch.slice_at(row).iter().cloned())
+                }
+            });
+
+            // Appending the halves back together should give back the original touch
+            let mut joined = first.clone();
+
+            joined.append_iterator(&second.iter().transfigure(&first.leftover_change));
+
+            assert_eq!(joined, touch);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_past_end() {
+        Touch::from("123\n213\n231").split_at(3);
+    }
+
+    #[test]
+    fn contains() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MAJOR));
+
+        assert!(plain_course.contains(&Change::rounds(Stage::MAJOR)));
+        assert!(plain_course.contains(&Change::from("13527486")));
+        assert!(plain_course.contains(&Change::from("87654321")));
+
+        // Course heads of other courses
+        assert!(!plain_course.contains(&Change::from("14235678")));
+        assert!(!plain_course.contains(&Change::from("13425678")));
+        // Rows of the wrong stage
+        assert!(!plain_course.contains(&Change::rounds(Stage::MINOR)));
+
+        // The leftover change isn't one of the rows
+        let lead = &plain_bob.plain_lead;
+
+        assert!(!lead.contains(&lead.leftover_change));
+    }
+
+    #[test]
+    fn distinct_row_counts() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+
+        assert_eq!(plain_course.distinct_row_count(), plain_course.length);
+
+        // Ringing the plain course with an extra lead repeats that lead's rows
+        let mut long_course = plain_course.clone();
+
+        long_course.push_lead(&plain_bob.place_notations);
+
+        assert_eq!(long_course.distinct_row_count(), plain_course.length);
+
+        // A single repeated row
+        let rows: Vec<Change> = ["123456", "214365", "123456", "132546"]
+            .iter()
+            .map(|r| Change::from(*r))
+            .collect();
+        let touch = Touch::from_changes(&rows, Change::rounds(Stage::MINOR));
+
+        assert_eq!(touch.distinct_row_count(), touch.length - 1);
+        assert_eq!(Touch::empty(Stage::MINOR).distinct_row_count(), 0);
+    }
+
+    #[test]
+    fn valid_extents() {
+        let bob_doubles = Method::from_str("Bob Doubles", "5.1.5.1.5,125", Stage::DOUBLES);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "145", Stage::DOUBLES);
+        let single = Call::lead_end_call_from_place_notation_string('s', "123", Stage::DOUBLES);
+
+        assert!(extent_touch(&bob_doubles, &[bob, single])
+            .unwrap()
+            .is_extent_valid());
+        assert!(Touch::from("123\n213\n231\n321\n312\n132\n123").is_extent_valid());
+
+        // Four plain courses of Grandsire Doubles have the right length and come round, but only
+        // contain (even) rows from the plain course
+        let grandsire = PlaceNotation::from_multiple_string("3,1.5.1.5.1", Stage::DOUBLES);
+        let pns: Vec<PlaceNotation> = grandsire.iter().cycle().take(120).cloned().collect();
+        let four_courses = Touch::from(&pns[..]);
+
+        assert_eq!(four_courses.length, 120);
+        assert!(four_courses.leftover_change.is_rounds());
+        assert!(!four_courses.is_extent_valid());
+
+        // Every row, but in an order that can't be rung
+        let rows: Vec<Change> = extent(Stage::MINOR).collect();
+
+        assert!(!Touch::from_changes(&rows, rows[0].clone()).is_extent_valid());
+
+        // Jumps between rows aren't allowed, even in a true extent
+        assert!(!Touch::from("123\n231\n312\n213\n132\n321\n123").is_extent_valid());
+
+        // The length of an extent on these stages doesn't fit in a `usize`
+        for (stage, pn) in &[(Stage::DECUPLES, "1"), (Stage::TWENTY_TWO, "x")] {
+            let rounds = Change::rounds(*stage);
+            let pns = PlaceNotation::from_multiple_string(pn, *stage);
+
+            assert!(!Touch::from_changes(&[rounds.clone()], rounds).is_extent_valid());
+            assert!(!Touch::from(&pns[..]).is_extent_valid());
+        }
+    }
+
+    #[test]
+    fn backrounds_wraps() {
+        for (rows, wraps) in &[
+            (vec!["12348765", "43215678"], 1),
+            (vec!["12345876", "54321678", "87654321"], 1),
+            (vec!["18765432", "23456781"], 0),
+            (vec!["12345678", "87654321", "12345678"], 0),
+            (vec!["12345678", "12345678", "12345678"], 0),
+            (vec!["12348765", "43218765", "43215678"], 2),
+        ] {
+            let changes: Vec<Change> = rows.iter().map(|r| Change::from(*r)).collect();
+            let touch = Touch::from_changes(&changes, Change::rounds(Stage::MAJOR));
+
+            assert_eq!(touch.count_backrounds_wraps(), *wraps);
+        }
+
+        // The plain course has backrounds wrapped over `165432 164523`
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+
+        assert_eq!(
+            Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR))
+                .count_backrounds_wraps(),
+            1
+        );
+    }
+
+    #[test]
+    fn music_signatures() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+        let scheme = MusicScheme {
+            four_bell_run: 1,
+            five_bell_run: 2,
+            named_row: 5,
+            cru: 3,
+            wrap: 0,
+        };
+
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MAJOR));
+
+        // Start the plain course from its third lead
+        let (first_leads, last_leads) = plain_course.split_at(48);
+        let mut rotated = Touch::with_capacity(Stage::MAJOR, 0, 0, 0, 0);
+
+        rotated.append_iterator(&last_leads.iter().transfigure(&first_leads.leftover_change));
+        rotated.append_iterator(&first_leads.iter());
+
+        assert_ne!(rotated, plain_course);
+        assert_eq!(
+            rotated.music_signature(&scheme),
+            plain_course.music_signature(&scheme)
+        );
+
+        // Calling a bob changes the rows and so the music
+        let bobbed_course = one_part_spliced_touch(&[("P", &plain_bob)], &[('-', bob)], "P-PPPPPP");
+
+        assert_ne!(
+            bobbed_course.music_signature(&scheme),
+            plain_course.music_signature(&scheme)
+        );
+
+        // Unscored music doesn't affect the signature
+        let runs_only = MusicScheme {
+            four_bell_run: 1,
+            ..MusicScheme::default()
+        };
+
+        assert_eq!(plain_course.music_signature(&runs_only)[1..], [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn method_changes() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+        let cambridge = Method::from_str(
+            "Cambridge Surprise Major",
+            "x38x14x1258x36x14x58x16x78,12",
+            Stage::MAJOR,
+        );
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        let methods = [("P", &plain_bob), ("C", &cambridge)];
+        let calls = [('-', bob)];
+
+        let touch = one_part_spliced_touch(&methods, &calls, "PPCC");
+
+        assert_eq!(touch.method_changes(&[16, 16, 32, 32]), vec![32]);
+        assert_eq!(
+            one_part_spliced_touch(&methods, &calls, "PCCP").method_changes(&[16, 32, 32, 16]),
+            vec![16, 80]
+        );
+
+        // Finer segments still only report the one splice
+        assert_eq!(touch.method_changes(&[8; 12]), vec![32]);
+        assert!(one_part_spliced_touch(&methods, &calls, "CC")
+            .method_changes(&[32, 32])
+            .is_empty());
+    }
+
+    #[test]
+    fn handbell_pair_path() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+
+        let path = plain_course.handbell_pair_path((Bell::from('5'), Bell::from('6')));
+
+        assert_eq!(path.len(), plain_course.length);
+        assert_eq!(
+            path[..4],
+            [
+                (Place::from(4), Place::from(5)),
+                (Place::from(5), Place::from(4)),
+                (Place::from(5), Place::from(3)),
+                (Place::from(4), Place::from(2)),
+            ]
+        );
+
+        for (i, (a, b)) in path.iter().enumerate() {
+            let row = plain_course.row_at(i);
+
+            assert_ne!(a, b);
+            assert_eq!(row.bell_at(*a), Bell::from('5'));
+            assert_eq!(row.bell_at(*b), Bell::from('6'));
+        }
+    }
+
+    #[test]
+    fn bell_svg_path() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+
+        let path = plain_course.bell_svg_path(Bell::from('3'), 10.0, 20.0);
+        let points: Vec<&str> = path.split(|c| c == 'M' || c == 'L').skip(1).collect();
+
+        assert_eq!(points.len(), plain_course.length);
+        assert!(path.starts_with("M 25 10 L 35 30 L 45 50 L 55 70 L 55 90 "));
+
+        // Every point should be in the centre of the cell containing the 3
+        for (i, point) in points.iter().enumerate() {
+            let coords: Vec<f32> = point
+                .split_whitespace()
+                .map(|x| x.parse().unwrap())
+                .collect();
+
+            assert_eq!(
+                coords,
+                vec![
+                    (plain_course.row_at(i).place_of(Bell::from('3')).as_usize() as f32 + 0.5)
+                        * 10.0,
+                    (i as f32 + 0.5) * 20.0
+                ]
+            );
+        }
+
+        assert_eq!(
+            Touch::with_capacity(Stage::MINOR, 0, 0, 0, 0).bell_svg_path(Bell::from('1'), 1.0, 1.0),
+            ""
+        );
+    }
+
+    #[test]
+    fn rounds_strokes() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+
+        assert!(plain_course.starts_with_rounds_at(Stroke::Back));
+        assert!(!plain_course.starts_with_rounds_at(Stroke::Hand));
+        assert!(plain_course.ends_with_rounds_at(Stroke::Back));
+        assert!(plain_course.is_valid_test_piece(Stroke::Back, Stroke::Back));
+        assert!(!plain_course.is_valid_test_piece(Stroke::Hand, Stroke::Back));
+
+        // Started from handstroke rounds, and brought round at handstroke
+        let touch = Touch::from("12345\n12345\n21354\n12345");
+
+        assert!(touch.starts_with_rounds_at(Stroke::Hand));
+        assert!(!touch.starts_with_rounds_at(Stroke::Back));
+        assert!(touch.ends_with_rounds_at(Stroke::Hand));
+        assert!(touch.is_valid_test_piece(Stroke::Hand, Stroke::Hand));
+
+        // Doesn't start or end with rounds at all
+        let touch = Touch::from("21354\n12345\n21354");
+
+        assert!(!touch.starts_with_rounds_at(Stroke::Hand));
+        assert!(!touch.starts_with_rounds_at(Stroke::Back));
+        assert!(!touch.ends_with_rounds_at(Stroke::Hand));
+        assert!(!touch.ends_with_rounds_at(Stroke::Back));
+    }
+
+    #[test]
+    fn row_strings() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+        let (first_half, _) = plain_course.split_at(17);
+
+        for touch in &[plain_course, first_half] {
+            let strings = touch.to_row_strings();
+            let string_refs: Vec<&str> = strings.iter().map(|s| s.as_str()).collect();
+
+            assert_eq!(strings.len(), touch.length + 1);
+
+            let round_tripped = Touch::from_row_strings(&string_refs).unwrap();
+
+            assert_eq!(round_tripped.length, touch.length);
+            assert_eq!(round_tripped.leftover_change, touch.leftover_change);
+            assert_eq!(round_tripped.to_row_strings(), strings);
+        }
+
+        assert_eq!(
+            Touch::from_row_strings(&["1234"]).unwrap().to_row_strings(),
+            vec!["1234"]
+        );
+        // Lowercase bell names are read the same as uppercase ones
+        assert_eq!(
+            Touch::from_row_strings(&["1234567890et", "2143658709te"])
+                .unwrap()
+                .to_row_strings(),
+            vec!["1234567890ET", "2143658709TE"]
+        );
+
+        for (rows, err) in &[
+            (vec![], RowStringsError::Empty),
+            (
+                vec!["123", "213", "1223"],
+                RowStringsError::InvalidRow(2, InvalidRowError::RepeatedBell(Bell::from('2'))),
+            ),
+            (
+                vec!["123", "21x"],
+                RowStringsError::InvalidRow(1, InvalidRowError::IllegalBell('x')),
+            ),
+            (vec!["123", "2134"], RowStringsError::WrongStage(1)),
+        ] {
+            assert_eq!(Touch::from_row_strings(rows), Err(*err));
+        }
+    }
+
+    #[test]
+    fn handstroke_gaps() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let plain_course = Touch::single_course(&plain_bob, &Change::rounds(Stage::MINOR));
+        let rows = plain_course.with_handstroke_gap();
+
+        assert_eq!(rows.len(), 90);
+
+        // Each gap comes between a backstroke and the following handstroke
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(row.is_none(), i % 3 == 1);
+        }
+
+        let rung_rows: Vec<Change> = rows.into_iter().flatten().collect();
+
+        assert_eq!(rung_rows.len(), plain_course.length);
+        assert!(rung_rows
+            .iter()
+            .zip(plain_course.row_iterator())
+            .all(|(a, b)| a.slice() == b.slice()));
+
+        assert_eq!(
+            Touch::from(
+                "123
+213
+123"
+            )
+            .with_handstroke_gap(),
+            vec![Some(Change::from("123")), None, Some(Change::from("213"))]
+        );
+        assert!(Touch::empty(Stage::MINOR).with_handstroke_gap().is_empty());
+    }
+
     const TOUCH_STRINGS: [&str; 4] = [
         "123456\n214365\n123456",
         "123\n213\n231\n321\n312\n132\n123",
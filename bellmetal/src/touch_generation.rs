@@ -1,8 +1,26 @@
-use crate::{Call, Change, Method, Touch, TouchIterator, Transposition};
+use crate::{
+    Call, Change, ChangeAccumulator, Method, MusicScheme, Touch, TouchIterator, Transposition,
+};
 
+use factorial::Factorial;
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
-use std::iter::repeat;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::iter::{once, repeat};
+
+// The largest stage on which `extent_touch` will attempt to search for an extent
+const MAX_EXTENT_SEARCH_STAGE: usize = 6;
+// The most leads that `search_touches` will put into each part of a touch
+const MAX_SEARCH_LEADS_PER_PART: usize = 8;
+// The most leads that `find_calling_for_length` will try before giving up
+const MAX_CALLING_SEARCH_NODES: usize = 1_000_000;
+// The most leads that `all_true_touches` will put into a touch, however many are asked for
+const MAX_TRUE_TOUCH_LEADS: usize = 16;
+// The most leads that `all_true_touches` will try before stopping
+const MAX_TRUE_TOUCH_SEARCH_NODES: usize = 1_000_000;
+// The most lead heads that `reachable_lead_heads` will collect before stopping
+const MAX_REACHABLE_LEAD_HEADS: usize = 1_000_000;
+// The largest stage on which the searches will allocate a table with a slot for every row
+const MAX_ROW_TABLE_STAGE: usize = 10;
 
 pub fn single_method_touch(method: &Method, mnemonic: &str, calls: &[Vec<&Call>]) -> Touch {
     one_part_spliced_touch_from_indices(repeat((mnemonic, method)).take(calls.len()), calls)
@@ -165,10 +183,453 @@ pub fn one_part_spliced_touch_from_indices<'a>(
     touch
 }
 
+// Every possible way of ringing a lead of `method` (plain, or ending with one of `calls`), along
+// with the rows of that lead when started from rounds
+fn lead_variants<'a>(method: &Method, calls: &'a [Call]) -> (Vec<Vec<&'a Call>>, Vec<Touch>) {
+    let lead_calls: Vec<Vec<&Call>> = once(Vec::new())
+        .chain(calls.iter().map(|c| vec![c]))
+        .collect();
+    let leads: Vec<Touch> = lead_calls
+        .iter()
+        .map(|c| method.get_lead_fragment_with_calls(0, method.lead_length(), c.iter().cloned()))
+        .collect();
+
+    (lead_calls, leads)
+}
+
+// A table with a slot for every row on `method`'s stage (indexed by `Change::destructive_hash`),
+// for the searches to mark which rows they have used.  Returns `None` if the stage is too large
+// for the table to fit in memory.
+fn row_table(method: &Method) -> Option<Vec<bool>> {
+    let stage = method.stage.as_usize();
+
+    if stage > MAX_ROW_TABLE_STAGE {
+        return None;
+    }
+
+    Some(vec![false; stage.factorial()])
+}
+
+// Marks the rows of `lead` (rung from `lead_head`) as used in `is_row_used`, returning the hashes
+// that were marked so that `remove_lead` can unmark them.  If `lead` is false against the rows
+// already used, nothing is marked and `None` is returned.
+fn try_add_lead(lead_head: &Change, lead: &Touch, is_row_used: &mut [bool]) -> Option<Vec<usize>> {
+    let mut row_hashes: Vec<usize> = Vec::with_capacity(lead.length);
+
+    for row in lead.row_iterator() {
+        let hash = lead_head.multiply(&row).destructive_hash();
+
+        if is_row_used[hash] {
+            remove_lead(&row_hashes, is_row_used);
+
+            return None;
+        }
+
+        is_row_used[hash] = true;
+        row_hashes.push(hash);
+    }
+
+    Some(row_hashes)
+}
+
+// Unmarks the rows added by `try_add_lead`
+fn remove_lead(row_hashes: &[usize], is_row_used: &mut [bool]) {
+    for h in row_hashes {
+        is_row_used[*h] = false;
+    }
+}
+
+// Searches for a true extent of `method` made up of plain leads and leads ending with one of
+// `calls`.  Returns `None` if no such extent exists, or if the stage is too large to search.
+pub fn extent_touch(method: &Method, calls: &[Call]) -> Option<Touch> {
+    let stage = method.stage.as_usize();
+    let lead_length = method.lead_length();
+
+    if stage > MAX_EXTENT_SEARCH_STAGE || lead_length == 0 {
+        return None;
+    }
+
+    let extent_length: usize = (1..=stage).product();
+    let num_leads = extent_length / lead_length;
+
+    if num_leads * lead_length != extent_length {
+        return None;
+    }
+
+    let (lead_calls, leads) = lead_variants(method, calls);
+
+    let mut is_row_used = row_table(method)?;
+    let mut choices: Vec<usize> = Vec::with_capacity(num_leads);
+    // Extents are small enough to always be searched exhaustively
+    let mut nodes_left = usize::MAX;
+
+    if !search_for_calling(
+        &leads,
+        &Change::rounds(method.stage),
+        num_leads,
+        &mut is_row_used,
+        &mut choices,
+        &mut nodes_left,
+    ) {
+        return None;
+    }
+
+    let touch_calls: Vec<Vec<&Call>> = choices.iter().map(|&i| lead_calls[i].clone()).collect();
+
+    Some(single_method_touch(method, &method.name, &touch_calls))
+}
+
+// Depth-first search for the rest of a true calling that comes round after exactly `num_leads`
+// leads, given the leads chosen so far (in `choices`) and which rows they have used up.  Each lead
+// tried uses up one of `nodes_left`, and the search gives up once there are none left.
+fn search_for_calling(
+    leads: &[Touch],
+    lead_head: &Change,
+    num_leads: usize,
+    is_row_used: &mut [bool],
+    choices: &mut Vec<usize>,
+    nodes_left: &mut usize,
+) -> bool {
+    if choices.len() == num_leads {
+        return lead_head.is_rounds();
+    }
+
+    for (i, lead) in leads.iter().enumerate() {
+        if *nodes_left == 0 {
+            return false;
+        }
+
+        *nodes_left -= 1;
+
+        if let Some(row_hashes) = try_add_lead(lead_head, lead, is_row_used) {
+            choices.push(i);
+
+            if search_for_calling(
+                leads,
+                &lead_head.multiply(&lead.leftover_change),
+                num_leads,
+                is_row_used,
+                choices,
+                nodes_left,
+            ) {
+                return true;
+            }
+
+            choices.pop();
+            remove_lead(&row_hashes, is_row_used);
+        }
+    }
+
+    false
+}
+
+// Searches for a true calling of `method` that comes round after exactly `target_rows` rows, as a
+// list of lead indices and the call made at the end of that lead.  Returns `None` if no such
+// calling is found within `MAX_CALLING_SEARCH_NODES` leads of searching, or if the stage is above
+// `MAX_ROW_TABLE_STAGE`.
+pub fn find_calling_for_length(
+    method: &Method,
+    calls: &[Call],
+    target_rows: usize,
+) -> Option<Vec<(usize, Call)>> {
+    let lead_length = method.lead_length();
+
+    if lead_length == 0 || target_rows == 0 {
+        return None;
+    }
+
+    let num_leads = target_rows / lead_length;
+
+    if num_leads * lead_length != target_rows {
+        return None;
+    }
+
+    let (lead_calls, leads) = lead_variants(method, calls);
+
+    let mut is_row_used = row_table(method)?;
+    let mut choices: Vec<usize> = Vec::with_capacity(num_leads);
+    let mut nodes_left = MAX_CALLING_SEARCH_NODES;
+
+    if !search_for_calling(
+        &leads,
+        &Change::rounds(method.stage),
+        num_leads,
+        &mut is_row_used,
+        &mut choices,
+        &mut nodes_left,
+    ) {
+        return None;
+    }
+
+    Some(
+        choices
+            .iter()
+            .enumerate()
+            .flat_map(|(lead, &i)| lead_calls[i].iter().map(move |c| (lead, (*c).clone())))
+            .collect(),
+    )
+}
+
+// Searches for true touches of `method` made up of `parts` identical parts of at most
+// `MAX_SEARCH_LEADS_PER_PART` leads, returning every one that scores at least `min_score`.  Leads
+// are pruned as soon as they are false against the part so far, and nothing is returned on stages
+// above `MAX_ROW_TABLE_STAGE`.
+pub fn search_touches(
+    method: &Method,
+    calls: &[Call],
+    parts: usize,
+    min_score: i64,
+    scheme: &MusicScheme,
+) -> Vec<Touch> {
+    if parts == 0 || method.lead_length() == 0 {
+        return Vec::new();
+    }
+
+    let mut is_row_used = match row_table(method) {
+        Some(table) => table,
+        None => return Vec::new(),
+    };
+    let (lead_calls, leads) = lead_variants(method, calls);
+
+    let mut search = TouchSearch {
+        method,
+        lead_calls,
+        leads,
+        parts,
+        min_score,
+        scheme,
+        touches: Vec::new(),
+    };
+
+    search.search(
+        &Change::rounds(method.stage),
+        &mut is_row_used,
+        &mut Vec::with_capacity(MAX_SEARCH_LEADS_PER_PART),
+    );
+
+    search.touches
+}
+
+struct TouchSearch<'a> {
+    method: &'a Method,
+    lead_calls: Vec<Vec<&'a Call>>,
+    leads: Vec<Touch>,
+    parts: usize,
+    min_score: i64,
+    scheme: &'a MusicScheme,
+    touches: Vec<Touch>,
+}
+
+impl TouchSearch<'_> {
+    // Depth-first search over the leads of the first part, given the leads chosen so far (in
+    // `choices`) and which rows they have used up
+    fn search(&mut self, part_end: &Change, is_row_used: &mut [bool], choices: &mut Vec<usize>) {
+        if !choices.is_empty() && self.is_valid_part_end(part_end) {
+            let touch_calls: Vec<Vec<&Call>> = (0..self.parts)
+                .flat_map(|_| choices.iter())
+                .map(|&i| self.lead_calls[i].clone())
+                .collect();
+            let touch = single_method_touch(self.method, &self.method.name, &touch_calls);
+
+            if touch.is_true() && self.scheme.score_touch(&touch) >= self.min_score {
+                self.touches.push(touch);
+            }
+        }
+
+        if choices.len() == MAX_SEARCH_LEADS_PER_PART {
+            return;
+        }
+
+        for i in 0..self.leads.len() {
+            // Prune any lead that is false against the part so far
+            if let Some(row_hashes) = try_add_lead(part_end, &self.leads[i], is_row_used) {
+                let next_part_end = part_end.multiply(&self.leads[i].leftover_change);
+
+                choices.push(i);
+                self.search(&next_part_end, is_row_used, choices);
+                choices.pop();
+                remove_lead(&row_hashes, is_row_used);
+            }
+        }
+    }
+
+    // A part end is only valid if the touch first comes round after exactly `parts` parts
+    fn is_valid_part_end(&self, part_end: &Change) -> bool {
+        let mut accumulator = ChangeAccumulator::new(part_end.stage());
+
+        for _ in 0..self.parts - 1 {
+            accumulator.accumulate(part_end);
+
+            if accumulator.total().is_rounds() {
+                return false;
+            }
+        }
+
+        accumulator.accumulate(part_end);
+
+        accumulator.total().is_rounds()
+    }
+}
+
+// Every lead head that can be reached from rounds by ringing leads of `method` that are either
+// plain or end with one of `calls`, found by a breadth-first search (so rounds comes first).  The
+// search stops after `MAX_REACHABLE_LEAD_HEADS` lead heads have been found.
+pub fn reachable_lead_heads(method: &Method, calls: &[Call]) -> Vec<Change> {
+    let lead_head_transpositions: Vec<Change> = once(method.lead_head().clone())
+        .chain(calls.iter().map(|c| method.lead_head_after_call(c)))
+        .collect();
+
+    let rounds = Change::rounds(method.stage);
+    let mut lead_heads = vec![rounds.clone()];
+    let mut seen: HashSet<Change> = once(rounds.clone()).collect();
+    let mut queue: VecDeque<Change> = once(rounds).collect();
+
+    while let Some(lead_head) = queue.pop_front() {
+        for transposition in &lead_head_transpositions {
+            if lead_heads.len() >= MAX_REACHABLE_LEAD_HEADS {
+                return lead_heads;
+            }
+
+            let next_lead_head = lead_head.multiply(transposition);
+
+            if seen.insert(next_lead_head.clone()) {
+                lead_heads.push(next_lead_head.clone());
+                queue.push_back(next_lead_head);
+            }
+        }
+    }
+
+    lead_heads
+}
+
+// Lazily generates every true touch of `method` that comes round within `max_leads` leads (made
+// up of plain leads and leads ending with one of `calls`), in depth-first order.  The search is
+// limited to `MAX_TRUE_TOUCH_LEADS` leads per touch and `MAX_TRUE_TOUCH_SEARCH_NODES` leads in
+// total, after which the iterator stops.  Nothing is generated on stages above
+// `MAX_ROW_TABLE_STAGE`.
+pub fn all_true_touches<'a>(
+    method: &'a Method,
+    calls: &'a [Call],
+    max_leads: usize,
+) -> impl Iterator<Item = Touch> + 'a {
+    let (lead_calls, leads) = lead_variants(method, calls);
+    // On stages too large for a row table, the search stack starts empty and nothing is generated
+    let is_row_used = row_table(method).unwrap_or_default();
+    let next_choices = if is_row_used.is_empty() {
+        Vec::new()
+    } else {
+        vec![0]
+    };
+
+    TrueTouchIterator {
+        method,
+        lead_calls,
+        leads,
+        max_leads: max_leads.min(MAX_TRUE_TOUCH_LEADS),
+        nodes_left: MAX_TRUE_TOUCH_SEARCH_NODES,
+        is_row_used,
+        lead_heads: vec![Change::rounds(method.stage)],
+        next_choices,
+        choices: Vec::new(),
+        row_hashes: Vec::new(),
+    }
+}
+
+struct TrueTouchIterator<'a> {
+    method: &'a Method,
+    lead_calls: Vec<Vec<&'a Call>>,
+    leads: Vec<Touch>,
+    max_leads: usize,
+    nodes_left: usize,
+    is_row_used: Vec<bool>,
+    // The search stack.  For each lead of the touch so far (plus the lead being chosen), these
+    // hold the lead head and the next lead variant to try
+    lead_heads: Vec<Change>,
+    next_choices: Vec<usize>,
+    // The lead variants chosen so far, and the hashes of the rows that each of them added
+    choices: Vec<usize>,
+    row_hashes: Vec<Vec<usize>>,
+}
+
+impl TrueTouchIterator<'_> {
+    // Undoes the most recently chosen lead, returning its rows to the pool
+    fn pop_choice(&mut self) {
+        self.choices.pop();
+
+        if let Some(row_hashes) = self.row_hashes.pop() {
+            remove_lead(&row_hashes, &mut self.is_row_used);
+        }
+    }
+}
+
+impl Iterator for TrueTouchIterator<'_> {
+    type Item = Touch;
+
+    fn next(&mut self) -> Option<Touch> {
+        loop {
+            let depth = self.choices.len();
+            let choice = *self.next_choices.last()?;
+
+            // Backtrack once every variant of this lead has been tried
+            if choice == self.leads.len() || depth == self.max_leads {
+                self.lead_heads.pop();
+                self.next_choices.pop();
+
+                if self.next_choices.is_empty() {
+                    return None;
+                }
+
+                self.pop_choice();
+
+                continue;
+            }
+
+            if self.nodes_left == 0 {
+                return None;
+            }
+
+            self.nodes_left -= 1;
+            self.next_choices[depth] += 1;
+
+            let lead_head = &self.lead_heads[depth];
+            let row_hashes =
+                match try_add_lead(lead_head, &self.leads[choice], &mut self.is_row_used) {
+                    Some(row_hashes) => row_hashes,
+                    None => continue,
+                };
+            let next_lead_head = lead_head.multiply(&self.leads[choice].leftover_change);
+
+            self.choices.push(choice);
+            self.row_hashes.push(row_hashes);
+
+            if next_lead_head.is_rounds() {
+                let touch_calls: Vec<Vec<&Call>> = self
+                    .choices
+                    .iter()
+                    .map(|&i| self.lead_calls[i].clone())
+                    .collect();
+
+                self.pop_choice();
+
+                return Some(single_method_touch(
+                    self.method,
+                    &self.method.name,
+                    &touch_calls,
+                ));
+            } else {
+                self.lead_heads.push(next_lead_head);
+                self.next_choices.push(0);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        one_part_spliced_touch, Call, Change, DefaultScoring, Method, Stage, TouchIterator,
+        all_true_touches, extent_touch, find_calling_for_length, one_part_spliced_touch,
+        reachable_lead_heads, search_touches, single_method_touch, Call, Change, DefaultScoring,
+        Method, MusicScheme, Stage, TouchIterator, Transposition,
     };
 
     #[test]
@@ -252,4 +713,131 @@ mod tests {
 
         assert_eq!(touch.leftover_change, Change::rounds(Stage::MAJOR));
     }
+
+    #[test]
+    fn extent_search() {
+        let bob_doubles = Method::from_str("Plain Bob Doubles", "5.1.5.1.5,125", Stage::DOUBLES);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "145", Stage::DOUBLES);
+        let single = Call::lead_end_call_from_place_notation_string('s', "123", Stage::DOUBLES);
+
+        let touch = extent_touch(&bob_doubles, &[bob, single]).unwrap();
+
+        assert_eq!(touch.length, 120);
+        assert_eq!(touch.leftover_change, Change::rounds(Stage::DOUBLES));
+        assert!(touch.is_true());
+
+        // The plain course comes round after 40 changes
+        assert_eq!(extent_touch(&bob_doubles, &[]), None);
+
+        // Major is too large to search
+        let plain_bob = Method::from_str("Plain Bob Major", "-18-18-18-18,12", Stage::MAJOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MAJOR);
+
+        assert_eq!(extent_touch(&plain_bob, &[bob]), None);
+    }
+
+    #[test]
+    fn touch_search() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MINOR);
+
+        let scheme = MusicScheme {
+            four_bell_run: 1,
+            five_bell_run: 2,
+            ..MusicScheme::default()
+        };
+
+        let touches = search_touches(&plain_bob, &[bob, single], 3, 20, &scheme);
+
+        assert!(!touches.is_empty());
+
+        for touch in &touches {
+            assert!(touch.is_true());
+            assert_eq!(touch.leftover_change, Change::rounds(Stage::MINOR));
+            assert_eq!(touch.length % 36, 0);
+            assert!(scheme.score_touch(touch) >= 20);
+        }
+
+        // No touch can score more than the number of rows allows
+        assert!(search_touches(&plain_bob, &[], 1, 1000, &scheme).is_empty());
+    }
+
+    #[test]
+    fn lead_head_groups() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MINOR);
+
+        // Plain leads only reach the plain course
+        assert_eq!(reachable_lead_heads(&plain_bob, &[]).len(), 5);
+        // Bobs only reach the even rows with the treble at lead, and singles reach the odd ones too
+        assert_eq!(reachable_lead_heads(&plain_bob, &[bob.clone()]).len(), 60);
+
+        let lead_heads = reachable_lead_heads(&plain_bob, &[bob, single]);
+
+        assert_eq!(lead_heads.len(), 120);
+        assert!(lead_heads[0].is_rounds());
+        assert!(lead_heads.iter().all(|lh| lh.treble_in_lead()));
+    }
+
+    #[test]
+    fn true_touches() {
+        let plain_bob = Method::from_str("Plain Bob Minor", "x16x16x16,12", Stage::MINOR);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "14", Stage::MINOR);
+        let single = Call::lead_end_call_from_place_notation_string('s', "1234", Stage::MINOR);
+        let calls = [bob, single];
+
+        let touches: Vec<_> = all_true_touches(&plain_bob, &calls, 6).collect();
+
+        for touch in &touches {
+            assert!(touch.is_true());
+            assert_eq!(touch.leftover_change, Change::rounds(Stage::MINOR));
+            assert!(touch.length <= 6 * 12);
+        }
+
+        // The plain course and three bobs at home are both found
+        assert!(touches
+            .iter()
+            .any(|t| t.length == 60 && t.to_compact(&plain_bob).unwrap().calling == "ppppp"));
+        assert!(touches.iter().any(|t| t.length == 36));
+
+        // The plain course is the only touch without calls
+        assert_eq!(all_true_touches(&plain_bob, &[], 10).count(), 1);
+        assert_eq!(all_true_touches(&plain_bob, &[], 4).count(), 0);
+        assert_eq!(all_true_touches(&plain_bob, &calls, 0).count(), 0);
+    }
+
+    #[test]
+    fn calling_for_length() {
+        let bob_doubles = Method::from_str("Plain Bob Doubles", "5.1.5.1.5,125", Stage::DOUBLES);
+        let bob = Call::lead_end_call_from_place_notation_string('-', "145", Stage::DOUBLES);
+        let single = Call::lead_end_call_from_place_notation_string('s', "123", Stage::DOUBLES);
+        let calls = [bob, single];
+
+        let calling = find_calling_for_length(&bob_doubles, &calls, 120).unwrap();
+
+        // Rebuild the touch from the calling, and check that it's a true 120
+        let mut touch_calls = vec![Vec::new(); 12];
+
+        for (lead, call) in &calling {
+            touch_calls[*lead].push(call);
+        }
+
+        let touch = single_method_touch(&bob_doubles, "P", &touch_calls);
+
+        assert_eq!(touch.length, 120);
+        assert_eq!(touch.leftover_change, Change::rounds(Stage::DOUBLES));
+        assert!(touch.is_true());
+
+        // The plain course needs no calls
+        assert_eq!(
+            find_calling_for_length(&bob_doubles, &calls, 40),
+            Some(Vec::new())
+        );
+
+        // Lengths that aren't a whole number of leads, or longer than the extent, are impossible
+        assert_eq!(find_calling_for_length(&bob_doubles, &calls, 125), None);
+        assert_eq!(find_calling_for_length(&bob_doubles, &calls, 130), None);
+    }
 }
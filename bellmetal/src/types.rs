@@ -35,6 +35,38 @@ impl Not for Parity {
     }
 }
 
+impl fmt::Display for Parity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Parity::Even => write!(f, "Even"),
+            Parity::Odd => write!(f, "Odd"),
+        }
+    }
+}
+
+impl FromStr for Parity {
+    type Err = UnknownParityError;
+
+    fn from_str(string: &str) -> Result<Parity, Self::Err> {
+        match string {
+            "Even" => Ok(Parity::Even),
+            "Odd" => Ok(Parity::Odd),
+            _ => Err(UnknownParityError),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Hash, Debug, PartialEq, Eq)]
+pub struct UnknownParityError;
+
+impl fmt::Display for UnknownParityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown parity name")
+    }
+}
+
+impl error::Error for UnknownParityError {}
+
 #[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
 pub enum Stroke {
     Back = 0,
@@ -80,6 +112,9 @@ pub trait MaskMethods {
     fn get(&self, value: Number) -> bool;
     fn del(&mut self, value: Number);
     fn add(&mut self, value: Number);
+
+    // Clears every bit at or above `stage`
+    fn truncate_to(&mut self, stage: Stage);
 }
 
 impl fmt::Debug for Mask {
@@ -120,10 +155,20 @@ impl MaskMethods for MaskStruct {
     fn add(&mut self, value: Number) {
         self.mask |= (1 as MaskType) << value
     }
+
+    fn truncate_to(&mut self, stage: Stage) {
+        if stage.as_number() < Mask::limit() {
+            self.mask &= ((1 as MaskType) << stage.as_number()) - 1;
+        }
+    }
 }
 
 pub type Number = u32;
 
+// `Bell`s, `Place`s and `Stage`s are all stored as plain numbers.  `Bell`s and `Place`s count from
+// 0, so `Bell::from(0)` is the treble and `Place::from(0)` is lead, whereas their names (as used in
+// place notation and when displaying rows) count from 1.  Use `from_number_one_indexed` to convert
+// from the numbers that ringers use.
 macro_rules! define_int_synonymn {
     ($type:ident) => {
         #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
@@ -246,6 +291,16 @@ static STAGE_NAMES: [&str; 23] = [
 ];
 
 impl Stage {
+    // The stages that have names for ringing, ordered from Singles up to Twenty-Two
+    pub fn all_named() -> impl Iterator<Item = Stage> {
+        (Stage::SINGLES.as_usize()..STAGE_NAMES.len()).map(Stage::from)
+    }
+
+    // The name of this stage, or `None` if it's too large to have one
+    pub fn name(&self) -> Option<&'static str> {
+        STAGE_NAMES.get(self.0 as usize).copied()
+    }
+
     pub fn write_to_string(&self, string: &mut String) {
         if self.0 as usize >= STAGE_NAMES.len() {
             string.push_str("<stage ");
@@ -301,6 +356,43 @@ impl From<char> for Bell {
     }
 }
 
+// Bells and places share a representation, but converting between them should always be
+// deliberate; these make that intent visible rather than going through `as_number`
+impl Bell {
+    pub fn as_place(&self) -> Place {
+        Place(self.0)
+    }
+
+    // Creates a bell from the number that ringers would give it, so `1` is the treble
+    pub fn from_number_one_indexed(number: Number) -> Bell {
+        if number == 0 {
+            panic!("Bell numbers start from 1");
+        }
+
+        Bell(number - 1)
+    }
+
+    // The bells of rounds on a given stage, from the treble to the tenor
+    pub fn rounds_vec(stage: Stage) -> Vec<Bell> {
+        (0..stage.0).map(Bell).collect()
+    }
+}
+
+impl Place {
+    pub fn as_bell(&self) -> Bell {
+        Bell(self.0)
+    }
+
+    // Creates a place from the number that ringers would give it, so `1` is lead
+    pub fn from_number_one_indexed(number: Number) -> Place {
+        if number == 0 {
+            panic!("Place numbers start from 1");
+        }
+
+        Place(number - 1)
+    }
+}
+
 #[cfg(test)]
 mod stage_tests {
     use crate::types::UnknownStageError;
@@ -328,11 +420,30 @@ mod stage_tests {
 
         assert_eq!(Stage::from(100).to_string(), "<stage 100>");
     }
+
+    #[test]
+    fn named_stages() {
+        let stages: Vec<Stage> = Stage::all_named().collect();
+
+        assert_eq!(stages.len(), 20);
+        assert_eq!(stages.first(), Some(&Stage::SINGLES));
+        assert_eq!(stages.last(), Some(&Stage::TWENTY_TWO));
+
+        for s in &stages {
+            assert_eq!(s.name().map(Stage::from_str), Some(Ok(*s)));
+        }
+
+        assert_eq!(Stage::MAJOR.name(), Some("Major"));
+        assert_eq!(Stage::TWENTY_TWO.name(), Some("Twenty-Two"));
+        assert_eq!(Stage::from(23).name(), None);
+    }
 }
 
 #[cfg(test)]
 mod parity_tests {
+    use crate::types::UnknownParityError;
     use crate::Parity;
+    use std::str::FromStr;
 
     #[test]
     fn not() {
@@ -347,6 +458,19 @@ mod parity_tests {
         assert_eq!(Parity::Odd * Parity::Even, Parity::Odd);
         assert_eq!(Parity::Odd * Parity::Odd, Parity::Even);
     }
+
+    #[test]
+    fn string_conversions() {
+        assert_eq!(Parity::Even.to_string(), "Even");
+        assert_eq!(Parity::Odd.to_string(), "Odd");
+
+        for p in &[Parity::Even, Parity::Odd] {
+            assert_eq!(Parity::from_str(&p.to_string()), Ok(*p));
+        }
+
+        assert_eq!(Parity::from_str("even"), Err(UnknownParityError));
+        assert_eq!(Parity::from_str(""), Err(UnknownParityError));
+    }
 }
 
 #[cfg(test)]
@@ -391,11 +515,58 @@ mod tests {
     panic_too_large_string_conversion!(too_large_conversion_place, Place, 10000);
     panic_too_large_string_conversion!(too_large_conversion_bell, Bell, 10000);
     panic_too_large_string_conversion!(too_large_conversion_stage, Stage, 10000);
+
+    #[test]
+    fn bell_place_conversions() {
+        for i in 0..64usize {
+            assert_eq!(Bell::from(i).as_place(), Place::from(i));
+            assert_eq!(Place::from(i).as_bell(), Bell::from(i));
+            assert_eq!(Bell::from(i).as_place().as_bell(), Bell::from(i));
+        }
+
+        assert_eq!(Bell::from('1').as_place().as_char(), '1');
+        assert_eq!(Bell::from('T').as_place().as_usize(), 11);
+    }
+
+    #[test]
+    fn one_indexed_conversions() {
+        assert_eq!(Bell::from_number_one_indexed(1), Bell::from('1'));
+        assert_eq!(Bell::from_number_one_indexed(1), Bell::from(0));
+        assert_eq!(Bell::from_number_one_indexed(12), Bell::from('T'));
+        assert_eq!(Place::from_number_one_indexed(1), Place::from(0));
+        assert_eq!(Place::from_number_one_indexed(8).as_char(), '8');
+
+        for i in 1..=64 {
+            assert_eq!(Bell::from_number_one_indexed(i).as_number(), i - 1);
+        }
+    }
+
+    #[test]
+    fn rounds_vec() {
+        for stage in Stage::all_named() {
+            let rounds = Bell::rounds_vec(stage);
+
+            assert_eq!(rounds.len(), stage.as_usize());
+            assert_eq!(*rounds.last().unwrap(), Bell::from(stage.as_usize() - 1));
+        }
+
+        assert_eq!(
+            Bell::rounds_vec(Stage::MINOR),
+            "123456".chars().map(Bell::from).collect::<Vec<_>>()
+        );
+        assert!(Bell::rounds_vec(Stage::ZERO).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_one_indexed_bell() {
+        Bell::from_number_one_indexed(0);
+    }
 }
 
 #[cfg(test)]
 mod mask_tests {
-    use crate::{Mask, MaskMethods};
+    use crate::{Mask, MaskMethods, Stage};
 
     #[test]
     fn empty_limit() {
@@ -460,4 +631,29 @@ mod mask_tests {
             "0001100100000000000000000000000000000000000000000000000000000000"
         );
     }
+
+    #[test]
+    fn truncate_to() {
+        let mut mask = Mask::from_bitmask(u64::MAX);
+
+        mask.truncate_to(Stage::MAJOR);
+
+        assert_eq!(mask, Mask::from_bitmask(0b1111_1111u64));
+
+        for i in 0..Mask::limit() {
+            assert_eq!(mask.get(i), i < 8);
+        }
+
+        // Truncating to the limit (or beyond) shouldn't change anything
+        let mut mask = Mask::from_bitmask(0b1010_0001u64);
+
+        mask.truncate_to(Stage::from(64));
+        assert_eq!(mask, Mask::from_bitmask(0b1010_0001u64));
+
+        mask.truncate_to(Stage::from(5));
+        assert_eq!(mask, Mask::from_bitmask(0b0000_0001u64));
+
+        mask.truncate_to(Stage::ZERO);
+        assert_eq!(mask, Mask::empty());
+    }
 }
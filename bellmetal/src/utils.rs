@@ -1,4 +1,4 @@
-use crate::{Bell, Change, Stage};
+use crate::{Bell, Change, Method, Stage};
 use std::iter::{Fuse, Peekable};
 
 pub fn closure(change: &Change) -> Vec<Change> {
@@ -18,6 +18,12 @@ pub fn closure(change: &Change) -> Vec<Change> {
     vec
 }
 
+// Returns `true` if `row` is one of the lead heads of `method`'s plain course, i.e. it is in the
+// group generated by the plain lead head
+pub fn is_in_plain_course_group(row: &Change, method: &Method) -> bool {
+    row.stage() == method.stage && closure(method.lead_head()).contains(row)
+}
+
 pub fn extent(stage: Stage) -> impl Iterator<Item = Change> {
     ExtentIterator::new(stage)
 }
@@ -194,7 +200,7 @@ static EXTENTS: [&[u8]; CACHED_EXTENTS] = [
 #[cfg(test)]
 mod tests {
     use crate::utils::AndNext;
-    use crate::{closure, extent, Change, Stage};
+    use crate::{closure, extent, is_in_plain_course_group, Change, Method, Stage};
 
     use factorial::Factorial;
 
@@ -246,6 +252,24 @@ mod tests {
         assert_eq!(closure(&Change::from("")), vec![Change::from(""),]);
     }
 
+    #[test]
+    fn plain_course_group() {
+        let plain_bob = Method::from_str("Plain Bob Major", "x18x18x18x18,12", Stage::MAJOR);
+
+        for lead_head in &[
+            "12345678", "13527486", "15738264", "17856342", "18674523", "16482735", "14263857",
+        ] {
+            assert!(is_in_plain_course_group(
+                &Change::from(*lead_head),
+                &plain_bob
+            ));
+        }
+
+        for row in &["13254768", "12436578", "13572468", "1234567", "123456789"] {
+            assert!(!is_in_plain_course_group(&Change::from(*row), &plain_bob));
+        }
+    }
+
     #[test]
     fn extent_gen() {
         for s in 1..9usize {